@@ -0,0 +1,266 @@
+/********************************************************************************************************************
+* ** Bundle Builder **
+* Oort only accepts a single pasted source file. The bundle keeps the tutorial module at the top level and
+* appends an inline `mod oort_core` holding only the oort_core modules reachable from it. Every `oort_core::`
+* path in the tutorial and every `crate::` path inside oort_core is rewritten to `crate::oort_core::`.
+*******************************************************************************************************************/
+use crate::source::SourceFile;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const PRELUDE_USE: &str = "use oort_api::prelude::*;";
+const CORE_PREFIX: &str = "oort_core::";
+const CRATE_PREFIX: &str = "crate::";
+const BUNDLED_PREFIX: &str = "crate::oort_core::";
+const TEST_ATTRIBUTES: [&str; 2] = ["#[cfg(test)]", "#[test]"];
+
+pub struct Bundler {
+    pub core_dir: PathBuf,
+}
+
+impl Bundler {
+    pub fn create(core_dir: PathBuf) -> Bundler {
+        Bundler { core_dir }
+    }
+
+    pub fn bundle(&self, entry_path: &Path) -> Result<String, String> {
+        let entry = SourceFile::parse(strip_test_items(&read_source(entry_path)?));
+        let mut pending = module_references(&entry, CORE_PREFIX)?;
+        let mut modules = BTreeSet::new();
+        let mut bodies = Vec::new();
+        while let Some(name) = pending.pop() {
+            if !modules.insert(name.clone()) {
+                continue;
+            }
+            let module = SourceFile::parse(strip_test_items(&self.read_module(&name)?));
+            pending.extend(module_references(&module, CRATE_PREFIX)?);
+            bodies.push((name, module));
+        }
+        bodies.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut output = String::new();
+        output.push_str(&format!("// Bundled from {} -- edit the sources, not this file\n", entry_path.display()));
+        output.push_str(PRELUDE_USE);
+        output.push_str("\n\n");
+        output.push_str(rewrite_entry(&entry).trim());
+        output.push_str("\n\n#[allow(dead_code)]\nmod oort_core {\n");
+        for (index, (name, module)) in bodies.iter().enumerate() {
+            if index > 0 {
+                output.push('\n');
+            }
+            let module = SourceFile::parse(rewrite_paths(module, CRATE_PREFIX));
+            output.push_str(&format!("    pub mod {} {{\n", name));
+            output.push_str(&indent(&module, "        "));
+            output.push_str("    }\n");
+        }
+        output.push_str("}\n");
+        Ok(output)
+    }
+
+    fn read_module(&self, name: &str) -> Result<String, String> {
+        let path = self.core_dir.join(format!("{}.rs", name));
+        if !path.is_file() {
+            return Err(format!("oort_core module `{}` not found at {}", name, path.display()));
+        }
+        read_source(&path)
+    }
+}
+
+fn read_source(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|error| format!("failed to read {}: {}", path.display(), error))
+}
+
+// Module names used right after `prefix`, including every entry of a `prefix{a, b::c}` group
+fn module_references(file: &SourceFile, prefix: &str) -> Result<Vec<String>, String> {
+    let bytes = file.text.as_bytes();
+    let mut names = Vec::new();
+    for start in file.find_code(prefix) {
+        let index = file.skip_whitespace(start + prefix.len());
+        if bytes.get(index) != Some(&b'{') {
+            names.extend(file.read_ident(index).map(String::from));
+            continue;
+        }
+        let close = file.matching_close(index).ok_or_else(|| format!("unclosed `{}{{` group", prefix))?;
+        let mut depth = 0;
+        let mut segment_start = index + 1;
+        for (position, byte) in bytes.iter().enumerate().take(close).skip(index) {
+            if !file.is_code(position) {
+                continue;
+            }
+            match byte {
+                b'{' => {
+                    depth += 1;
+                    continue;
+                }
+                b'}' => {
+                    depth -= 1;
+                    if depth > 0 {
+                        continue;
+                    }
+                }
+                b',' if depth == 1 => {}
+                _ => continue,
+            }
+            names.extend(file.read_ident(file.skip_whitespace(segment_start)).map(String::from));
+            segment_start = position + 1;
+        }
+    }
+    names.retain(|name| name != "self");
+    Ok(names)
+}
+
+// Drops `#[cfg(test)]` items and `#[test]` fns, since the game never builds them
+fn strip_test_items(text: &str) -> String {
+    let file = SourceFile::parse(text.to_string());
+    let mut attributes: Vec<(usize, &str)> = TEST_ATTRIBUTES
+        .iter()
+        .flat_map(|attribute| file.find_code(attribute).into_iter().map(move |start| (start, *attribute)))
+        .collect();
+    attributes.sort();
+    let mut output = String::new();
+    let mut copied = 0;
+    for (start, attribute) in attributes {
+        if start < copied {
+            continue; // nested inside an item already removed
+        }
+        let line_start = text[..start].rfind('\n').map_or(0, |index| index + 1);
+        let Some(mut end) = file.item_end(start + attribute.len()) else {
+            continue;
+        };
+        if text[end..].starts_with('\n') {
+            end += 1;
+        }
+        output.push_str(&text[copied..line_start]);
+        copied = end;
+    }
+    output.push_str(&text[copied..]);
+    output
+}
+
+// Points every `prefix` path at the inlined oort_core module
+fn rewrite_paths(file: &SourceFile, prefix: &str) -> String {
+    let mut output = String::new();
+    let mut copied = 0;
+    for start in file.find_code(prefix) {
+        output.push_str(&file.text[copied..start]);
+        output.push_str(BUNDLED_PREFIX);
+        copied = start + prefix.len();
+    }
+    output.push_str(&file.text[copied..]);
+    output
+}
+
+// The entry's prelude import is dropped, the bundle opens with it instead
+fn rewrite_entry(entry: &SourceFile) -> String {
+    rewrite_paths(entry, CORE_PREFIX)
+        .lines()
+        .filter(|line| line.trim() != PRELUDE_USE)
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+// Indents a module body, leaving the continuation lines of multi-line literals untouched
+fn indent(module: &SourceFile, prefix: &str) -> String {
+    let text = module.text.trim_end();
+    let mut output = String::new();
+    let mut line_start = 0;
+    for line in text.split('\n') {
+        if line_start > 0 && module.is_literal(line_start - 1) {
+            output.push_str(line);
+        } else if !line.trim().is_empty() {
+            output.push_str(prefix);
+            output.push_str(line);
+        }
+        output.push('\n');
+        line_start += line.len() + 1;
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn parse(text: &str) -> SourceFile {
+        SourceFile::parse(text.to_string())
+    }
+
+    // A scratch oort_core source directory holding `modules`
+    fn core_dir(name: &str, modules: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bundler-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for (module, text) in modules {
+            fs::write(dir.join(format!("{}.rs", module)), text).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn module_references_reads_grouped_imports() {
+        let file = parse("use crate::{radar, turning::{self, HybridTurn}, guidance::LineOfSight};\nuse crate::constants::TICK;\n");
+        let mut names = module_references(&file, CRATE_PREFIX).unwrap();
+        names.sort();
+        assert_eq!(names, ["constants", "guidance", "radar", "turning"]);
+        assert!(module_references(&parse("use crate::{radar, turning;"), CRATE_PREFIX).is_err());
+    }
+
+    #[test]
+    fn strip_test_items_removes_test_modules_and_fns() {
+        let text = "fn kept() {}\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn inner() {}\n}\n#[test]\nfn loose() { assert!(true); }\nfn also_kept() {}\n";
+        assert_eq!(strip_test_items(text), "fn kept() {}\nfn also_kept() {}\n");
+        let quoted = "const NAME: &str = \"#[test]\";\n";
+        assert_eq!(strip_test_items(quoted), quoted);
+    }
+
+    #[test]
+    fn rewrite_paths_skips_comments_and_literals() {
+        let file = parse("use crate::radar; // crate::radar\nconst PATH: &str = \"crate::radar\";\n/* crate::x */ let x = $crate::y;\n");
+        assert_eq!(
+            rewrite_paths(&file, CRATE_PREFIX),
+            "use crate::oort_core::radar; // crate::radar\nconst PATH: &str = \"crate::radar\";\n/* crate::x */ let x = $crate::y;\n"
+        );
+    }
+
+    #[test]
+    fn modules_are_included_transitively() {
+        let dir = core_dir(
+            "transitive",
+            &[
+                ("alpha", "use crate::beta::Beta;\npub struct Alpha(pub Beta);\n"),
+                ("beta", "pub struct Beta(pub crate::gamma::Gamma);\n"),
+                ("gamma", "pub struct Gamma;\n"),
+                ("unused", "pub struct Unused;\n"),
+            ],
+        );
+        let entry = dir.join("entry.rs");
+        fs::write(&entry, "use oort_core::alpha::Alpha;\n").unwrap();
+        let bundled = Bundler::create(dir.clone()).bundle(&entry).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(bundled.contains("use crate::oort_core::alpha::Alpha;"));
+        for module in ["alpha", "beta", "gamma"] {
+            assert!(bundled.contains(&format!("pub mod {} {{", module)), "{module} missing");
+        }
+        assert!(!bundled.contains("pub mod unused"));
+        assert!(bundled.contains("pub struct Beta(pub crate::oort_core::gamma::Gamma);"));
+    }
+
+    #[test]
+    fn bundled_tutorial_has_no_oort_core_paths_left() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let bundled = Bundler::create(root.join("oort_core/src"))
+            .bundle(&root.join("tutorials/src/squadron.rs"))
+            .unwrap();
+        let file = parse(&bundled);
+        assert!(!file.find_code(CORE_PREFIX).is_empty());
+        for start in file.find_code(CORE_PREFIX) {
+            assert!(
+                bundled[..start].ends_with(CRATE_PREFIX),
+                "unbundled path in `{}`",
+                bundled[start..].lines().next().unwrap_or("")
+            );
+        }
+        assert!(file.find_code("#[test]").is_empty() && file.find_code("#[cfg(test)]").is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+/**************************************************************
+* bundler
+* Author: Christopher Dean
+* Flattens one tutorial Ship plus the oort_core modules it
+* reaches into a single file that can be pasted into the
+* oort.rs editor.
+*
+* usage: bundler <entry.rs> [--core <oort_core/src>] [-o <out.rs>]
+****************************************************************/
+mod bundle;
+mod source;
+
+use bundle::Bundler;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::{env, fs};
+
+const USAGE: &str = "usage: bundler <entry.rs> [--core <oort_core/src>] [-o <out.rs>]";
+
+struct Options {
+    entry: PathBuf,
+    core_dir: PathBuf,
+    output: Option<PathBuf>,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut entry = None;
+    let mut core_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../oort_core/src");
+    let mut output = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--core" => core_dir = args.next().ok_or("--core needs a directory")?.into(),
+            "-o" | "--output" => output = Some(args.next().ok_or("-o needs a file")?.into()),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if entry.is_none() && !arg.starts_with('-') => entry = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument `{}`\n{}", arg, USAGE)),
+        }
+    }
+    let entry = entry.ok_or(USAGE)?;
+    Ok(Options { entry, core_dir, output })
+}
+
+fn run() -> Result<(), String> {
+    let options = parse_options(env::args().skip(1))?;
+    let bundled = Bundler::create(options.core_dir).bundle(&options.entry)?;
+    match options.output {
+        Some(path) => fs::write(&path, bundled).map_err(|error| format!("failed to write {}: {}", path.display(), error)),
+        None => {
+            print!("{}", bundled);
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,246 @@
+/********************************************************************************************************************
+* ** Source Scanner **
+* Just enough of a Rust lexer to tell code apart from comments and string literals, so the bundler
+* can search for module paths and strip items without tripping over text that only looks like code.
+*******************************************************************************************************************/
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Region {
+    Code,
+    Comment,
+    Literal,
+}
+
+/*****************************************************
+* SourceFile
+* Holds the original text alongside a per-byte map of
+* which region each byte belongs to
+*******************************************************/
+pub struct SourceFile {
+    pub text: String,
+    regions: Vec<Region>,
+}
+
+fn is_ident_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_'
+}
+
+impl SourceFile {
+    pub fn parse(text: String) -> SourceFile {
+        let regions = classify(text.as_bytes());
+        SourceFile { text, regions }
+    }
+
+    pub fn is_code(&self, index: usize) -> bool {
+        self.regions.get(index) == Some(&Region::Code)
+    }
+
+    pub fn is_literal(&self, index: usize) -> bool {
+        self.regions.get(index) == Some(&Region::Literal)
+    }
+
+    // Every code position where `needle` starts as a whole path segment (not the tail of a longer ident or `$crate`)
+    pub fn find_code(&self, needle: &str) -> Vec<usize> {
+        let bytes = self.text.as_bytes();
+        self.text
+            .match_indices(needle)
+            .map(|(index, _)| index)
+            .filter(|&index| self.is_code(index))
+            .filter(|&index| index == 0 || !(is_ident_byte(bytes[index - 1]) || bytes[index - 1] == b'$'))
+            .collect()
+    }
+
+    pub fn skip_whitespace(&self, mut index: usize) -> usize {
+        let bytes = self.text.as_bytes();
+        while index < bytes.len() && (bytes[index].is_ascii_whitespace() || self.regions[index] == Region::Comment) {
+            index += 1;
+        }
+        index
+    }
+
+    pub fn read_ident(&self, index: usize) -> Option<&str> {
+        let bytes = self.text.as_bytes();
+        let end = (index..bytes.len()).find(|&i| !is_ident_byte(bytes[i])).unwrap_or(bytes.len());
+        if end == index {
+            return None;
+        }
+        Some(&self.text[index..end])
+    }
+
+    // Index just past the delimiter that closes the one at `open`
+    pub fn matching_close(&self, open: usize) -> Option<usize> {
+        let bytes = self.text.as_bytes();
+        let mut depth = 0;
+        for (index, byte) in bytes.iter().enumerate().skip(open) {
+            if !self.is_code(index) {
+                continue;
+            }
+            match byte {
+                b'{' | b'(' | b'[' => depth += 1,
+                b'}' | b')' | b']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(index + 1);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    // Index just past the item starting at `start`: its closing brace, or its semicolon
+    pub fn item_end(&self, start: usize) -> Option<usize> {
+        let bytes = self.text.as_bytes();
+        let mut index = start;
+        while index < bytes.len() {
+            if self.is_code(index) {
+                match bytes[index] {
+                    b';' => return Some(index + 1),
+                    b'{' => return self.matching_close(index),
+                    b'(' | b'[' => {
+                        index = self.matching_close(index)?;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+            index += 1;
+        }
+        None
+    }
+}
+
+// Walks the source once and tags every byte as code, comment or literal
+fn classify(bytes: &[u8]) -> Vec<Region> {
+    let mut regions = vec![Region::Code; bytes.len()];
+    let mut index = 0;
+    while index < bytes.len() {
+        let start = index;
+        let region = match bytes[index] {
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                index = bytes[index..].iter().position(|&b| b == b'\n').map_or(bytes.len(), |offset| index + offset);
+                Region::Comment
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                index = block_comment_end(bytes, index);
+                Region::Comment
+            }
+            b'"' => {
+                index = string_end(bytes, index + 1);
+                Region::Literal
+            }
+            b'r' if (index == 0 || !is_ident_byte(bytes[index - 1])) && raw_string_hashes(bytes, index + 1).is_some() => {
+                index = raw_string_end(bytes, index + 1);
+                Region::Literal
+            }
+            b'\'' => match char_literal_end(bytes, index) {
+                Some(end) => {
+                    index = end;
+                    Region::Literal
+                }
+                None => {
+                    index += 1; // lifetime or label
+                    Region::Code
+                }
+            },
+            _ => {
+                index += 1;
+                Region::Code
+            }
+        };
+        regions[start..index].fill(region);
+    }
+    regions
+}
+
+fn block_comment_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0;
+    let mut index = start;
+    while index + 1 < bytes.len() {
+        match (bytes[index], bytes[index + 1]) {
+            (b'/', b'*') => {
+                depth += 1;
+                index += 2;
+            }
+            (b'*', b'/') => {
+                depth -= 1;
+                index += 2;
+                if depth == 0 {
+                    return index;
+                }
+            }
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+fn string_end(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return index + 1,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+// Number of `#` between an `r` and its opening quote, if this is a raw string at all
+fn raw_string_hashes(bytes: &[u8], start: usize) -> Option<usize> {
+    let hashes = bytes[start..].iter().take_while(|&&b| b == b'#').count();
+    (bytes.get(start + hashes) == Some(&b'"')).then_some(hashes)
+}
+
+fn raw_string_end(bytes: &[u8], start: usize) -> usize {
+    let hashes = raw_string_hashes(bytes, start).unwrap_or(0);
+    let mut index = start + hashes + 1;
+    while index < bytes.len() {
+        if bytes[index] == b'"' && bytes[index + 1..].iter().take(hashes).filter(|&&b| b == b'#').count() == hashes {
+            return index + 1 + hashes;
+        }
+        index += 1;
+    }
+    bytes.len()
+}
+
+// Tells 'x' and '\n' apart from a lifetime like 'a
+fn char_literal_end(bytes: &[u8], start: usize) -> Option<usize> {
+    if bytes.get(start + 1) == Some(&b'\\') {
+        let close = bytes[start + 3..].iter().position(|&b| b == b'\'')?;
+        return Some(start + 3 + close + 1);
+    }
+    let width = match bytes.get(start + 1)? {
+        byte if *byte < 0x80 => 1,
+        byte if *byte >= 0xf0 => 4,
+        byte if *byte >= 0xe0 => 3,
+        _ => 2,
+    };
+    (bytes.get(start + 1 + width) == Some(&b'\'')).then_some(start + 2 + width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions_tell_code_from_comments_and_literals() {
+        let file = SourceFile::parse("a /* b /* c */ d */ 'x' '\\n' r#\"e\"# fn f<'a>() // g\n\"h\\\"i\"".to_string());
+        let region_of = |needle: &str| file.regions[file.text.find(needle).unwrap()];
+        assert_eq!(region_of("a "), Region::Code);
+        assert_eq!(region_of("d */"), Region::Comment);
+        assert_eq!(region_of("'x'"), Region::Literal);
+        assert_eq!(region_of("'\\n'"), Region::Literal);
+        assert_eq!(region_of("e\"#"), Region::Literal);
+        assert_eq!(region_of("'a>"), Region::Code);
+        assert_eq!(region_of("g"), Region::Comment);
+        assert_eq!(region_of("i\""), Region::Literal);
+    }
+
+    #[test]
+    fn find_code_matches_whole_path_segments() {
+        let file = SourceFile::parse("crate::a; $crate::b; my_crate::c; // crate::d".to_string());
+        assert_eq!(file.find_code("crate::"), [0]);
+    }
+}
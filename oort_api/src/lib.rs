@@ -0,0 +1,63 @@
+/**************************************************************
+* oort_api (mock)
+* Author: Christopher Dean
+* Offline stand-in for the oort_api crate the game links ship
+* code against. Every game function in the prelude reads from
+* or records into a thread-local mock::Environment, so
+* Ship::new()/Ship::tick() can run under cargo test. Bundled
+* submissions are pasted into the game unchanged, where the
+* real oort_api takes its place.
+****************************************************************/
+pub mod mock;
+pub mod vec;
+
+// Radio payload, four `f64` slots wide like the game's radios.
+pub type Message = [f64; 4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Class {
+    Fighter,
+    Frigate,
+    Cruiser,
+    Asteroid,
+    Target,
+    Missile,
+    Torpedo,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScanResult {
+    pub class: Class,
+    pub position: vec::Vec2,
+    pub velocity: vec::Vec2,
+    pub rssi: f64,
+    pub snr: f64,
+}
+
+// Writes a line to the ship's debug panel.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        $crate::mock::record_debug(::std::format!($($arg)*))
+    };
+}
+
+// Draws text at a world position.
+#[macro_export]
+macro_rules! draw_text {
+    ($topleft:expr, $color:expr, $($arg:tt)*) => {
+        $crate::mock::record_text($topleft, $color, ::std::format!($($arg)*))
+    };
+}
+
+pub mod prelude {
+    pub use crate::mock::api::*;
+    pub use crate::vec::{vec2, Vec2};
+    pub use crate::{debug, draw_text};
+    pub use crate::{Class, Message, ScanResult};
+    pub use std::f64::consts::{PI, TAU};
+
+    // Length of one simulation tick in seconds.
+    pub const TICK_LENGTH: f64 = 1.0 / 60.0;
+}
@@ -0,0 +1,455 @@
+/********************************************************************************************************************
+* ** Mock World State **
+* Tests describe what the ship can observe by filling in an Environment, call into ship code,
+* then inspect the Commands it issued.
+*******************************************************************************************************************/
+use crate::vec::Vec2;
+use crate::{Class, Message, ScanResult};
+use std::cell::RefCell;
+use std::f64::consts::{PI, TAU};
+
+// Acceleration limits for a ship class, in m/s² and rad/s².
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    pub max_forward_acceleration: f64,
+    pub max_backward_acceleration: f64,
+    pub max_lateral_acceleration: f64,
+    pub max_angular_acceleration: f64,
+}
+
+impl Limits {
+    pub fn for_class(class: Class) -> Limits {
+        let (forward, backward, lateral, angular) = match class {
+            Class::Fighter => (60.0, 30.0, 30.0, TAU),
+            Class::Frigate => (10.0, 5.0, 5.0, PI / 4.0),
+            Class::Cruiser => (5.0, 2.5, 2.5, PI / 8.0),
+            Class::Missile => (300.0, 0.0, 100.0, 4.0 * PI),
+            Class::Torpedo => (70.0, 0.0, 20.0, 2.0 * PI),
+            _ => (0.0, 0.0, 0.0, 0.0),
+        };
+        Limits {
+            max_forward_acceleration: forward,
+            max_backward_acceleration: backward,
+            max_lateral_acceleration: lateral,
+            max_angular_acceleration: angular,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radar {
+    pub heading: f64,
+    pub width: f64,
+    pub min_distance: f64,
+    pub max_distance: f64,
+}
+
+impl Default for Radar {
+    fn default() -> Radar {
+        Radar {
+            heading: 0.0,
+            width: TAU / 60.0,
+            min_distance: 0.0,
+            max_distance: 1e9,
+        }
+    }
+}
+
+// One radio: the channel it listens/sends on and the message waiting to be received.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Radio {
+    pub channel: usize,
+    pub inbox: Option<Message>,
+}
+
+// A message a ship sent this tick.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transmission {
+    pub radio: usize,
+    pub channel: usize,
+    pub message: Message,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drawing {
+    Line {
+        from: Vec2,
+        to: Vec2,
+        color: u32,
+    },
+    Polygon {
+        center: Vec2,
+        size: f64,
+        sides: u32,
+        angle: f64,
+        color: u32,
+    },
+    Text {
+        topleft: Vec2,
+        color: u32,
+        text: String,
+    },
+}
+
+// Everything a ship asked the game to do during one call into its code.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Commands {
+    pub acceleration: Option<Vec2>,
+    pub torque: Option<f64>,
+    pub turn: Option<f64>,
+    pub fired: Vec<usize>,
+    pub aimed: Vec<(usize, f64)>,
+    pub sent: Vec<Transmission>,
+    pub exploded: bool,
+    pub debug: Vec<String>,
+    pub drawings: Vec<Drawing>,
+}
+
+// What the ship currently being ticked can observe, plus the commands it has issued.
+#[derive(Debug, Clone)]
+pub struct Environment {
+    pub class: Class,
+    pub id: u32,
+    pub seed: u64,
+    pub tick: u32,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub heading: f64,
+    pub angular_velocity: f64,
+    pub health: f64,
+    pub fuel: f64,
+    pub limits: Limits,
+    pub world_size: f64,
+    pub target: Vec2,
+    pub target_velocity: Vec2,
+    pub radar: Radar,
+    pub scan_result: Option<ScanResult>,
+    pub radios: Vec<Radio>,
+    pub selected_radio: usize,
+    pub reload_ticks: Vec<u32>,
+    pub rng: u64,
+    pub commands: Commands,
+}
+
+impl Environment {
+    pub fn for_class(class: Class) -> Environment {
+        let (radio_count, fuel, health) = match class {
+            Class::Fighter => (2, f64::INFINITY, 100.0),
+            Class::Frigate => (4, f64::INFINITY, 10000.0),
+            Class::Cruiser => (8, f64::INFINITY, 20000.0),
+            Class::Missile => (1, 2000.0, 20.0),
+            Class::Torpedo => (1, 1000.0, 100.0),
+            _ => (1, 0.0, 100.0),
+        };
+        Environment {
+            class,
+            id: 0,
+            seed: 0,
+            tick: 0,
+            position: Vec2::zero(),
+            velocity: Vec2::zero(),
+            heading: 0.0,
+            angular_velocity: 0.0,
+            health,
+            fuel,
+            limits: Limits::for_class(class),
+            world_size: 40000.0,
+            target: Vec2::zero(),
+            target_velocity: Vec2::zero(),
+            radar: Radar::default(),
+            scan_result: None,
+            radios: vec![Radio::default(); radio_count],
+            selected_radio: 0,
+            reload_ticks: vec![0; 4],
+            rng: 0x9e37_79b9_7f4a_7c15,
+            commands: Commands::default(),
+        }
+    }
+
+    // Seeds both `seed()` and the `rand()` stream.
+    pub fn with_seed(mut self, seed: u64) -> Environment {
+        self.seed = seed;
+        self.rng = seed ^ 0x9e37_79b9_7f4a_7c15;
+        self
+    }
+
+    fn radio(&mut self) -> &mut Radio {
+        let index = self.selected_radio.min(self.radios.len() - 1);
+        &mut self.radios[index]
+    }
+
+    // splitmix64, good enough for jitter and deterministic per seed
+    fn next_random(&mut self) -> f64 {
+        self.rng = self.rng.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl Default for Environment {
+    fn default() -> Environment {
+        Environment::for_class(Class::Fighter)
+    }
+}
+
+thread_local! {
+    static ENVIRONMENT: RefCell<Environment> = RefCell::new(Environment::default());
+}
+
+// Restores the default environment: a fighter at rest at the origin.
+pub fn reset() {
+    install(Environment::default());
+}
+
+// Replaces the current environment, returning the previous one.
+pub fn install(environment: Environment) -> Environment {
+    ENVIRONMENT.with(|cell| cell.replace(environment))
+}
+
+// Runs `f` with mutable access to the current environment.
+pub fn with<R>(f: impl FnOnce(&mut Environment) -> R) -> R {
+    ENVIRONMENT.with(|cell| f(&mut cell.borrow_mut()))
+}
+
+// Returns a copy of the current environment.
+pub fn environment() -> Environment {
+    ENVIRONMENT.with(|cell| cell.borrow().clone())
+}
+
+// Returns and clears the commands recorded since the last call.
+pub fn take_commands() -> Commands {
+    with(|env| std::mem::take(&mut env.commands))
+}
+
+fn get<R>(f: impl FnOnce(&Environment) -> R) -> R {
+    ENVIRONMENT.with(|cell| f(&cell.borrow()))
+}
+
+#[doc(hidden)]
+pub fn record_debug(text: String) {
+    with(|env| env.commands.debug.push(text));
+}
+
+#[doc(hidden)]
+pub fn record_text(topleft: Vec2, color: u32, text: String) {
+    with(|env| env.commands.drawings.push(Drawing::Text { topleft, color, text }));
+}
+
+// The game functions re-exported through `oort_api::prelude`.
+pub mod api {
+    use super::{get, with, Drawing, Transmission};
+    use crate::vec::Vec2;
+    use crate::{Class, Message, ScanResult};
+
+    pub fn class() -> Class {
+        get(|env| env.class)
+    }
+
+    pub fn id() -> u32 {
+        get(|env| env.id)
+    }
+
+    pub fn seed() -> u64 {
+        get(|env| env.seed)
+    }
+
+    pub fn current_tick() -> u32 {
+        get(|env| env.tick)
+    }
+
+    pub fn current_time() -> f64 {
+        get(|env| env.tick as f64 / 60.0)
+    }
+
+    pub fn world_size() -> f64 {
+        get(|env| env.world_size)
+    }
+
+    pub fn position() -> Vec2 {
+        get(|env| env.position)
+    }
+
+    pub fn velocity() -> Vec2 {
+        get(|env| env.velocity)
+    }
+
+    pub fn heading() -> f64 {
+        get(|env| env.heading)
+    }
+
+    pub fn angular_velocity() -> f64 {
+        get(|env| env.angular_velocity)
+    }
+
+    pub fn health() -> f64 {
+        get(|env| env.health)
+    }
+
+    pub fn fuel() -> f64 {
+        get(|env| env.fuel)
+    }
+
+    pub fn target() -> Vec2 {
+        get(|env| env.target)
+    }
+
+    pub fn target_velocity() -> Vec2 {
+        get(|env| env.target_velocity)
+    }
+
+    pub fn max_forward_acceleration() -> f64 {
+        get(|env| env.limits.max_forward_acceleration)
+    }
+
+    pub fn max_backward_acceleration() -> f64 {
+        get(|env| env.limits.max_backward_acceleration)
+    }
+
+    pub fn max_lateral_acceleration() -> f64 {
+        get(|env| env.limits.max_lateral_acceleration)
+    }
+
+    pub fn max_angular_acceleration() -> f64 {
+        get(|env| env.limits.max_angular_acceleration)
+    }
+
+    pub fn accelerate(acceleration: Vec2) {
+        with(|env| env.commands.acceleration = Some(acceleration));
+    }
+
+    pub fn torque(angular_acceleration: f64) {
+        with(|env| {
+            env.commands.torque = Some(angular_acceleration);
+            env.commands.turn = None;
+        });
+    }
+
+    pub fn turn(angular_velocity: f64) {
+        with(|env| {
+            env.commands.turn = Some(angular_velocity);
+            env.commands.torque = None;
+        });
+    }
+
+    pub fn fire(index: usize) {
+        with(|env| env.commands.fired.push(index));
+    }
+
+    pub fn aim(index: usize, heading: f64) {
+        with(|env| env.commands.aimed.push((index, heading)));
+    }
+
+    pub fn reload_ticks(index: usize) -> u32 {
+        get(|env| env.reload_ticks.get(index).copied().unwrap_or(0))
+    }
+
+    pub fn explode() {
+        with(|env| env.commands.exploded = true);
+    }
+
+    pub fn radar_heading() -> f64 {
+        get(|env| env.radar.heading)
+    }
+
+    pub fn set_radar_heading(heading: f64) {
+        with(|env| env.radar.heading = heading);
+    }
+
+    pub fn radar_width() -> f64 {
+        get(|env| env.radar.width)
+    }
+
+    pub fn set_radar_width(width: f64) {
+        with(|env| env.radar.width = width);
+    }
+
+    pub fn radar_min_distance() -> f64 {
+        get(|env| env.radar.min_distance)
+    }
+
+    pub fn set_radar_min_distance(distance: f64) {
+        with(|env| env.radar.min_distance = distance);
+    }
+
+    pub fn radar_max_distance() -> f64 {
+        get(|env| env.radar.max_distance)
+    }
+
+    pub fn set_radar_max_distance(distance: f64) {
+        with(|env| env.radar.max_distance = distance);
+    }
+
+    pub fn scan() -> Option<ScanResult> {
+        get(|env| env.scan_result)
+    }
+
+    pub fn select_radio(index: usize) {
+        with(|env| env.selected_radio = index);
+    }
+
+    pub fn get_radio_channel() -> usize {
+        with(|env| env.radio().channel)
+    }
+
+    pub fn set_radio_channel(channel: usize) {
+        with(|env| env.radio().channel = channel);
+    }
+
+    pub fn send(message: Message) {
+        with(|env| {
+            let radio = env.selected_radio;
+            let channel = env.radio().channel;
+            env.commands.sent.push(Transmission { radio, channel, message });
+        });
+    }
+
+    pub fn receive() -> Option<Message> {
+        with(|env| env.radio().inbox.take())
+    }
+
+    // Uniform random number in `[low, high)`, deterministic per `seed()`.
+    pub fn rand(low: f64, high: f64) -> f64 {
+        with(|env| low + (high - low) * env.next_random())
+    }
+
+    // Signed shortest rotation from heading `a` to heading `b`, in `[-PI, PI]`.
+    pub fn angle_diff(a: f64, b: f64) -> f64 {
+        let c = (b - a).rem_euclid(std::f64::consts::TAU);
+        if c > std::f64::consts::PI {
+            c - std::f64::consts::TAU
+        } else {
+            c
+        }
+    }
+
+    pub fn draw_line(from: Vec2, to: Vec2, color: u32) {
+        with(|env| env.commands.drawings.push(Drawing::Line { from, to, color }));
+    }
+
+    pub fn draw_polygon(center: Vec2, size: f64, sides: u32, angle: f64, color: u32) {
+        with(|env| {
+            env.commands.drawings.push(Drawing::Polygon {
+                center,
+                size,
+                sides,
+                angle,
+                color,
+            })
+        });
+    }
+
+    pub fn draw_triangle(center: Vec2, size: f64, color: u32) {
+        draw_polygon(center, size, 3, 0.0, color);
+    }
+
+    pub fn draw_square(center: Vec2, size: f64, color: u32) {
+        draw_polygon(center, size, 4, std::f64::consts::FRAC_PI_4, color);
+    }
+
+    pub fn draw_diamond(center: Vec2, size: f64, color: u32) {
+        draw_polygon(center, size, 4, 0.0, color);
+    }
+}
@@ -0,0 +1,170 @@
+/********************************************************************************************************************
+* ** Vec2 **
+* Minimal 2D vector matching the subset of oort_api::vec the solutions use.
+*******************************************************************************************************************/
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+pub fn vec2(x: f64, y: f64) -> Vec2 {
+    Vec2 { x, y }
+}
+
+impl Vec2 {
+    pub fn new(x: f64, y: f64) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn zero() -> Vec2 {
+        Vec2 { x: 0.0, y: 0.0 }
+    }
+
+    pub fn length(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    pub fn length_squared(self) -> f64 {
+        self.x * self.x + self.y * self.y
+    }
+
+    // Like the game, a zero vector normalizes to NaN
+    pub fn normalize(self) -> Vec2 {
+        self / self.length()
+    }
+
+    pub fn dot(self, other: Vec2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn cross(self, other: Vec2) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn distance(self, other: Vec2) -> f64 {
+        (self - other).length()
+    }
+
+    // Heading of the vector in radians, in `[0, TAU)` like the game reports.
+    pub fn angle(self) -> f64 {
+        let angle = self.y.atan2(self.x);
+        if angle < 0.0 {
+            angle + std::f64::consts::TAU
+        } else {
+            angle
+        }
+    }
+
+    pub fn rotate(self, angle: f64) -> Vec2 {
+        let (sin, cos) = angle.sin_cos();
+        vec2(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:.2}, {:.2})", self.x, self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+    fn add(self, other: Vec2) -> Vec2 {
+        vec2(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+    fn sub(self, other: Vec2) -> Vec2 {
+        vec2(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Vec2 {
+        vec2(-self.x, -self.y)
+    }
+}
+
+impl Mul<f64> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, scale: f64) -> Vec2 {
+        vec2(self.x * scale, self.y * scale)
+    }
+}
+
+impl Mul<Vec2> for f64 {
+    type Output = Vec2;
+    fn mul(self, v: Vec2) -> Vec2 {
+        v * self
+    }
+}
+
+// Component-wise product, used by a few of the older navigation heuristics.
+impl Mul<Vec2> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, other: Vec2) -> Vec2 {
+        vec2(self.x * other.x, self.y * other.y)
+    }
+}
+
+// Adds the scalar to both components.
+impl Add<f64> for Vec2 {
+    type Output = Vec2;
+    fn add(self, offset: f64) -> Vec2 {
+        vec2(self.x + offset, self.y + offset)
+    }
+}
+
+// Subtracts the scalar from both components.
+impl Sub<f64> for Vec2 {
+    type Output = Vec2;
+    fn sub(self, offset: f64) -> Vec2 {
+        vec2(self.x - offset, self.y - offset)
+    }
+}
+
+impl Div<f64> for Vec2 {
+    type Output = Vec2;
+    fn div(self, scale: f64) -> Vec2 {
+        vec2(self.x / scale, self.y / scale)
+    }
+}
+
+// Component-wise division, used by a few of the older navigation heuristics.
+impl Div<Vec2> for Vec2 {
+    type Output = Vec2;
+    fn div(self, other: Vec2) -> Vec2 {
+        vec2(self.x / other.x, self.y / other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign<f64> for Vec2 {
+    fn mul_assign(&mut self, scale: f64) {
+        *self = *self * scale;
+    }
+}
+
+impl DivAssign<f64> for Vec2 {
+    fn div_assign(&mut self, scale: f64) {
+        *self = *self / scale;
+    }
+}
@@ -0,0 +1,41 @@
+/*******************************************************************
+* ** Blackboard **
+* State that outlives a tick and is shared between a ship's
+* subsystems. The Ship owns one and lends it to each subsystem
+* as it ticks, so radar, radio and coordination can all read and
+* write the same track picture without statics or unsafe. The
+* game runs every ship in its own sandbox, so a blackboard only
+* ever holds what its own ship knows
+********************************************************************/
+use crate::coordination::HiveMind;
+use crate::radio::FleetPicture;
+use crate::tracking::TrackTable;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blackboard {
+    pub ticks: u32, // ticks this ship has run
+    pub tracks: TrackTable,
+    pub fleet: FleetPicture,
+    pub hive: HiveMind,
+}
+
+impl Blackboard {
+    pub fn create() -> Blackboard {
+        Blackboard {
+            ticks: 0,
+            tracks: TrackTable::create(),
+            fleet: FleetPicture::create(),
+            hive: HiveMind::create(),
+        }
+    }
+
+    pub fn with_tracks(mut self, tracks: TrackTable) -> Blackboard {
+        self.tracks = tracks;
+        self
+    }
+
+    // Call once at the top of Ship::tick
+    pub fn begin_tick(&mut self) {
+        self.ticks += 1;
+    }
+}
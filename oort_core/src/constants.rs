@@ -0,0 +1,21 @@
+/*****************************************************
+* Shared Constants
+* Units are meters, seconds and radians unless the
+* name says otherwise
+*******************************************************/
+pub const BULLET_SPEED: f64 = 1000.0; // m/s
+pub const MISSILE_SPEED: f64 = 850.0; // m/s
+pub const MISSILE_FUEL: f64 = 2000.0; // m/s of delta-v
+pub const MISSILE_BLAST_RADIUS: f64 = 150.0; // meters
+pub const TORPEDO_FUEL: f64 = 1000.0; // m/s of delta-v
+pub const TORPEDO_ACCELERATION: f64 = 70.0; // m/s^2
+pub const TORPEDO_BLAST_RADIUS: f64 = 300.0; // meters
+pub const TICKS_PER_SECOND: f64 = 60.0;
+pub const BULLET_SPEED_PER_TICK: f64 = BULLET_SPEED / TICKS_PER_SECOND;
+pub const BULLET_LIFETIME: f64 = 5.0; // seconds
+pub const TICKS_PER_FIRE: u32 = 4;
+pub const MISSILE_RELOAD_TIME: u32 = 120;
+pub const MISSILE_LOS_TUNE_FACTOR: f64 = 3.25;
+
+// Mission objectives
+pub const SEEK_AND_DESTROY: u64 = 1337;
@@ -0,0 +1,225 @@
+/*******************************************************************
+* ** Coordination **
+* The HiveMind runs on the lowest id fighter the squadron can
+* hear and hands every fighter a target. Each ship runs in its
+* own sandbox, so all it knows of the others arrives on the
+* command channel: every ship talks in its own tick slot,
+* alternating its position with its current target, and the
+* leader answers with assignments in its slot
+********************************************************************/
+use crate::radio::{MessageID, RadioMessage};
+use crate::targeting::UnitDescription;
+use crate::tracking::{Track, TrackId, TrackState, TrackTable};
+use oort_api::prelude::*;
+
+pub const REPORT_SLOTS: u32 = 8; // ticks in a round, each ship talks in one of them
+pub const FRIENDLY_TIMEOUT: f64 = 2.0; // seconds without a report before a ship is presumed dead
+pub const DISTANCE_WEIGHT: f64 = 1.0; // cost per kilometer to the target
+pub const ANGLE_WEIGHT: f64 = 1.0; // cost per radian the target is off the nose
+pub const LOAD_WEIGHT: f64 = 1.5; // cost per fighter already on the target
+pub const STICKINESS: f64 = 0.5; // taken off a fighter's current target so assignments do not flip
+const HEADING_RESOLUTION: f64 = 1.0 / 10000.0; // radians, position reports carry heading in the track field
+
+// Whether `id` talks on this tick
+pub fn is_report_tick(id: u32, tick: u32) -> bool {
+    tick % REPORT_SLOTS == id % REPORT_SLOTS
+}
+
+// Even rounds carry positions, odd rounds targets and assignments
+pub fn is_position_round(tick: u32) -> bool {
+    (tick / REPORT_SLOTS).is_multiple_of(2)
+}
+
+/*****************************************************
+* Friendly
+* A ship of ours as it last reported itself
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Friendly {
+    pub id: u32,
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub heading: f64,
+    pub last_heard: f64,
+}
+
+impl Friendly {
+    // This ship, right now
+    pub fn own() -> Friendly {
+        Friendly {
+            id: id(),
+            class: class(),
+            position: position(),
+            velocity: velocity(),
+            heading: heading(),
+            last_heard: current_time(),
+        }
+    }
+
+    // The sender of a position report
+    pub fn from_report(message: &RadioMessage, time: f64) -> Option<Friendly> {
+        (message.id == MessageID::Position).then(|| Friendly {
+            id: message.sender,
+            class: message.class,
+            position: message.extrapolated_position(),
+            velocity: message.velocity,
+            heading: message.track as f64 * HEADING_RESOLUTION,
+            last_heard: time,
+        })
+    }
+
+    pub fn position_at(&self, time: f64) -> Vec2 {
+        self.position + self.velocity * (time - self.last_heard)
+    }
+}
+
+// Our own position, velocity and heading for the rest of the squadron
+pub fn position_report() -> RadioMessage {
+    let heading = (heading().rem_euclid(TAU) / HEADING_RESOLUTION).round() as TrackId;
+    RadioMessage::create(MessageID::Position, heading, position(), velocity()).with_class(class())
+}
+
+// The target in an assignment message, if it is addressed to `ship`
+pub fn read_assignment(message: &RadioMessage, ship: u32) -> Option<UnitDescription> {
+    (message.id == MessageID::Assignment && message.track == ship)
+        .then(|| UnitDescription::create(message.class, message.extrapolated_position(), message.velocity, None))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Assignment {
+    pub ship: u32,
+    pub track: TrackId,
+    pub cost: f64,
+}
+
+/*****************************************************
+* HiveMind
+* Friendly ships heard over the radio, and which track
+* each fighter should engage. Assignment is greedy: the
+* cheapest fighter and target pair goes first, and
+* every fighter on a target makes it dearer for the
+* next, so the squadron spreads out
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct HiveMind {
+    pub friendlies: Vec<Friendly>,
+    pub assignments: Vec<Assignment>,
+    pub distance_weight: f64,
+    pub angle_weight: f64,
+    pub load_weight: f64,
+    pub stickiness: f64,
+    last_sent: Option<u32>,
+}
+
+impl HiveMind {
+    pub fn create() -> HiveMind {
+        HiveMind {
+            friendlies: Vec::new(),
+            assignments: Vec::new(),
+            distance_weight: DISTANCE_WEIGHT,
+            angle_weight: ANGLE_WEIGHT,
+            load_weight: LOAD_WEIGHT,
+            stickiness: STICKINESS,
+            last_sent: None,
+        }
+    }
+
+    pub fn with_weights(mut self, distance: f64, angle: f64, load: f64) -> HiveMind {
+        self.distance_weight = distance;
+        self.angle_weight = angle;
+        self.load_weight = load;
+        self
+    }
+
+    pub fn register_ship(&mut self, friendly: Friendly) {
+        match self.friendlies.iter_mut().find(|known| known.id == friendly.id) {
+            Some(known) => *known = friendly,
+            None => self.friendlies.push(friendly),
+        }
+    }
+
+    // Register the sender of a position report, anything else is ignored
+    pub fn hear(&mut self, message: &RadioMessage, time: f64) {
+        if let Some(friendly) = Friendly::from_report(message, time) {
+            self.register_ship(friendly);
+        }
+    }
+
+    // Drop ships that have gone quiet, and their assignments
+    pub fn forget(&mut self, time: f64) {
+        self.friendlies.retain(|friendly| time - friendly.last_heard <= FRIENDLY_TIMEOUT);
+        let friendlies = &self.friendlies;
+        self.assignments
+            .retain(|assignment| friendlies.iter().any(|friendly| friendly.id == assignment.ship));
+    }
+
+    // The lowest id we can hear, ourselves included
+    pub fn leader(&self, own_id: u32) -> u32 {
+        self.friendlies.iter().map(|friendly| friendly.id).fold(own_id, u32::min)
+    }
+
+    // Hand each fighter a target from the confirmed ship tracks. A target that dies
+    // stops being confirmed, and its fighters are reassigned
+    pub fn assign(&mut self, tracks: &TrackTable, time: f64) -> &[Assignment] {
+        let targets: Vec<&Track> = tracks
+            .tracks
+            .iter()
+            .filter(|track| track.state == TrackState::Confirmed && !matches!(track.class, Class::Missile | Class::Torpedo))
+            .collect();
+        let mut fighters: Vec<&Friendly> = self.friendlies.iter().filter(|friendly| friendly.class == Class::Fighter).collect();
+        let mut load = vec![0u32; targets.len()];
+        let mut assignments = Vec::with_capacity(fighters.len());
+        while !fighters.is_empty() && !targets.is_empty() {
+            let mut best: Option<(usize, usize, f64)> = None;
+            for (fighter_index, fighter) in fighters.iter().enumerate() {
+                for (target_index, target) in targets.iter().enumerate() {
+                    let cost = self.cost(fighter, target, load[target_index], time);
+                    if best.is_none_or(|(_, _, best_cost)| cost < best_cost) {
+                        best = Some((fighter_index, target_index, cost));
+                    }
+                }
+            }
+            let Some((fighter_index, target_index, cost)) = best else {
+                break;
+            };
+            let fighter = fighters.swap_remove(fighter_index);
+            load[target_index] += 1;
+            assignments.push(Assignment {
+                ship: fighter.id,
+                track: targets[target_index].id,
+                cost,
+            });
+        }
+        self.assignments = assignments;
+        &self.assignments
+    }
+
+    // Distance, angle off the nose and crowding, less a bonus for the current target
+    fn cost(&self, fighter: &Friendly, target: &Track, load: u32, time: f64) -> f64 {
+        let offset = target.position_at(time) - fighter.position_at(time);
+        let off_nose = angle_diff(fighter.heading, offset.angle()).abs();
+        let current = self.assignment(fighter.id).is_some_and(|assignment| assignment.track == target.id);
+        self.distance_weight * offset.length() / 1000.0 + self.angle_weight * off_nose + self.load_weight * load as f64
+            - if current { self.stickiness } else { 0.0 }
+    }
+
+    pub fn assignment(&self, ship: u32) -> Option<&Assignment> {
+        self.assignments.iter().find(|assignment| assignment.ship == ship)
+    }
+
+    // The next assignment to send, in ship id order, wrapping around
+    pub fn next_message(&mut self, tracks: &TrackTable, time: f64) -> Option<RadioMessage> {
+        let after = |assignment: &&Assignment| self.last_sent.is_none_or(|last| assignment.ship > last);
+        let assignment = *self
+            .assignments
+            .iter()
+            .filter(after)
+            .min_by_key(|assignment| assignment.ship)
+            .or_else(|| self.assignments.iter().min_by_key(|assignment| assignment.ship))?;
+        self.last_sent = Some(assignment.ship);
+        let track = tracks.get(assignment.track)?;
+        let message = RadioMessage::create(MessageID::Assignment, assignment.ship, track.position_at(time), track.velocity_at(time));
+        Some(message.with_class(track.class))
+    }
+}
@@ -0,0 +1,60 @@
+/********************************************************************************************************
+* ** Diagnostics **
+* Functions used to update the systems diagnostics
+*********************************************************************************************************/
+use oort_api::prelude::*;
+
+pub fn draw_ship_state() {
+    debug!("Angular Velocity: {}", angular_velocity());
+    debug!("Ships Heading {}", heading());
+    debug!("Ships Velocity {}", velocity());
+}
+
+pub fn draw_target_heading(target_heading: Option<f64>) {
+    if let Some(target_heading) = target_heading {
+        debug!("Target Heading {}", target_heading);
+    }
+}
+
+// Green line to the tutorial's target() marker
+pub fn draw_target_line() {
+    draw_line(position(), target(), 0x00ff00);
+}
+
+// Red line to the lead position plus the time of flight for the given projectile
+pub fn draw_lead(lead_position: Option<Vec2>, projectile_speed: f64, target_acceleration: Option<Vec2>) {
+    if let Some(lead_position) = lead_position {
+        let dp = lead_position - position();
+        debug!("distance to target: {} meters", dp.length());
+        debug!("time to target: {} seconds", dp.length() / projectile_speed);
+        if let Some(target_acceleration) = target_acceleration {
+            debug!("Target Acceleration: {} meters/s", target_acceleration);
+        }
+        draw_line(position(), lead_position, 0xff0000);
+    }
+}
+
+/*****************************************************
+* TurnTest
+* Picks a random mark every 120 ticks, used with the
+* !SEEK_AND_DESTROY objective to exercise turning
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TurnTest {
+    pub counter: f64,
+}
+
+impl TurnTest {
+    pub fn create() -> TurnTest {
+        TurnTest { counter: 120.0 }
+    }
+
+    pub fn next_mark(&mut self, current_mark: Option<Vec2>) -> Option<Vec2> {
+        if self.counter < 120.0 {
+            self.counter += 1.0;
+            return current_mark;
+        }
+        self.counter = 0.0;
+        Some(vec2(rand(-world_size(), world_size()), rand(-world_size(), world_size())))
+    }
+}
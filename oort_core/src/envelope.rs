@@ -0,0 +1,231 @@
+/*******************************************************************
+* ** Missile Envelope **
+* A missile's fuel is delta-v: every m/s of velocity change burns
+* one unit. To hit the target at time t the missile has to cover
+* the gap d(t) between where the target will be and where the
+* missile would coast to. Burning dv at full acceleration a and
+* then coasting covers dv*t - dv^2/(2a), so the cheapest burn that
+* closes the gap by time t is
+*   dv(t) = a*t - sqrt(a^2*t^2 - 2*a*|d(t)|)
+* The earliest t whose dv fits the budget is the intercept. The
+* missile boosts until it is on that course, coasts while its
+* course passes close enough for guidance to correct, and saves
+* a reserve for the terminal phase, where guidance takes over
+********************************************************************/
+use crate::constants::{MISSILE_FUEL, TICKS_PER_SECOND, TORPEDO_ACCELERATION, TORPEDO_FUEL};
+use crate::guidance::DEFAULT_MAX_ACCELERATION;
+use oort_api::prelude::*;
+
+pub const TERMINAL_TIME: f64 = 1.5; // seconds to go when guidance takes over
+pub const TERMINAL_RESERVE: f64 = 250.0; // m/s of delta-v the boost leaves for the terminal phase
+pub const COAST_MISS: f64 = 25.0; // meters, a coasting course passing this close is left for the terminal phase
+pub const MAX_FLIGHT_TIME: f64 = 20.0; // seconds, intercepts further out are not planned
+const TIME_STEP: f64 = 1.0 / TICKS_PER_SECOND;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissilePhase {
+    Boost,    // burning onto an intercept course
+    Coast,    // on course, engines off
+    Terminal, // close in, guidance flies the rest
+}
+
+// The hardest a class can pull away from a missile, m/s^2. Unknown contacts are
+// assumed to be fighters
+pub fn evasive_acceleration(class: Class) -> f64 {
+    match class {
+        Class::Frigate => 10.0,
+        Class::Cruiser => 5.0,
+        Class::Missile => 300.0,
+        Class::Torpedo => 70.0,
+        Class::Asteroid | Class::Target => 0.0,
+        Class::Fighter | Class::Unknown => 60.0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterceptPlan {
+    pub time: f64,       // seconds to intercept
+    pub delta_v: f64,    // m/s burned to get there
+    pub direction: Vec2, // unit vector to burn along, zero when already on target
+}
+
+// Unit vector along the gap, zero when the gap has closed and there is nothing to burn for
+fn burn_direction(gap: Vec2) -> Vec2 {
+    if gap.length() > 0.0 {
+        gap.normalize()
+    } else {
+        vec2(0.0, 0.0)
+    }
+}
+
+impl InterceptPlan {
+    // The acceleration for this tick of the boost, never burning past the plan
+    pub fn burn(&self, max_acceleration: f64) -> Vec2 {
+        self.direction * max_acceleration.min(self.delta_v * TICKS_PER_SECOND)
+    }
+}
+
+/*****************************************************
+* MissileEnergy
+* Fuel and thrust of one missile, and what it can
+* still reach with them
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MissileEnergy {
+    pub fuel: f64,
+    pub max_acceleration: f64,
+    pub terminal_reserve: f64,
+    pub max_flight_time: f64,
+}
+
+impl MissileEnergy {
+    pub fn create(fuel: f64, max_acceleration: f64) -> MissileEnergy {
+        MissileEnergy {
+            fuel,
+            max_acceleration,
+            terminal_reserve: TERMINAL_RESERVE,
+            max_flight_time: MAX_FLIGHT_TIME,
+        }
+    }
+
+    // A fresh missile, for the launcher to ask about before firing
+    pub fn missile() -> MissileEnergy {
+        MissileEnergy::create(MISSILE_FUEL, DEFAULT_MAX_ACCELERATION)
+    }
+
+    // A fresh missile or torpedo
+    pub fn for_class(class: Class) -> MissileEnergy {
+        match class {
+            Class::Torpedo => MissileEnergy::create(TORPEDO_FUEL, TORPEDO_ACCELERATION),
+            _ => MissileEnergy::missile(),
+        }
+    }
+
+    // This missile, in flight
+    pub fn current() -> MissileEnergy {
+        MissileEnergy::create(fuel(), max_forward_acceleration())
+    }
+
+    pub fn with_terminal_reserve(mut self, terminal_reserve: f64) -> MissileEnergy {
+        self.terminal_reserve = terminal_reserve;
+        self
+    }
+
+    pub fn with_max_flight_time(mut self, max_flight_time: f64) -> MissileEnergy {
+        self.max_flight_time = max_flight_time;
+        self
+    }
+
+    // The earliest intercept the whole tank can make, None if the target is out of reach
+    pub fn plan(&self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2, target_acceleration: Vec2) -> Option<InterceptPlan> {
+        self.plan_within(self.fuel, missile, missile_velocity, target, target_velocity, target_acceleration)
+    }
+
+    // Seconds to the target on the reserve-keeping boost the missile flies itself
+    pub fn flight_time(&self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2) -> Option<f64> {
+        let still = vec2(0.0, 0.0);
+        self.plan_within(
+            (self.fuel - self.terminal_reserve).max(0.0),
+            missile,
+            missile_velocity,
+            target,
+            target_velocity,
+            still,
+        )
+        .or_else(|| self.plan(missile, missile_velocity, target, target_velocity, still))
+        .map(|plan| plan.time)
+    }
+
+    // Whether the target can be hit if it holds its course
+    pub fn can_reach(&self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2) -> bool {
+        self.plan(missile, missile_velocity, target, target_velocity, vec2(0.0, 0.0)).is_some()
+    }
+
+    // Whether the target is inside the no-escape zone: it is hit even if it pulls
+    // its hardest straight away from the missile or off to either side
+    pub fn no_escape(&self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2, class: Class) -> bool {
+        if target == missile {
+            return true;
+        }
+        let away = (target - missile).normalize();
+        let acceleration = evasive_acceleration(class);
+        [away, vec2(-away.y, away.x), vec2(away.y, -away.x)].iter().all(|direction| {
+            self.plan(missile, missile_velocity, target, target_velocity, *direction * acceleration)
+                .is_some()
+        })
+    }
+
+    // Boost on the reserve-keeping plan, or the whole tank if that is all that reaches.
+    // The plan is None when the target is out of reach or already in the terminal phase
+    pub fn phase(
+        &self,
+        missile: Vec2,
+        missile_velocity: Vec2,
+        target: Vec2,
+        target_velocity: Vec2,
+        target_acceleration: Vec2,
+    ) -> (MissilePhase, Option<InterceptPlan>) {
+        let plan_within = |budget: f64| self.plan_within(budget, missile, missile_velocity, target, target_velocity, target_acceleration);
+        let Some(fastest) = plan_within(self.fuel) else {
+            return (MissilePhase::Terminal, None);
+        };
+        if fastest.time <= TERMINAL_TIME {
+            return (MissilePhase::Terminal, None);
+        }
+        if let Some(course) = self.coasting_course(missile, missile_velocity, target, target_velocity, target_acceleration) {
+            return (MissilePhase::Coast, Some(course));
+        }
+        let boost = plan_within((self.fuel - self.terminal_reserve).max(0.0)).unwrap_or(fastest);
+        (MissilePhase::Boost, Some(boost))
+    }
+
+    // The earliest intercept needing no more than `budget` of delta-v
+    fn plan_within(
+        &self,
+        budget: f64,
+        missile: Vec2,
+        missile_velocity: Vec2,
+        target: Vec2,
+        target_velocity: Vec2,
+        target_acceleration: Vec2,
+    ) -> Option<InterceptPlan> {
+        let steps = (self.max_flight_time / TIME_STEP).ceil() as u32;
+        (1..=steps).find_map(|step| {
+            let time = step as f64 * TIME_STEP;
+            let gap = target + target_velocity * time + target_acceleration * (0.5 * time * time) - (missile + missile_velocity * time);
+            let delta_v = self.delta_v(gap.length(), time)?;
+            (delta_v <= budget).then(|| InterceptPlan {
+                time,
+                delta_v,
+                direction: burn_direction(gap),
+            })
+        })
+    }
+
+    // Where the engines-off course passes closest to the target, if that is inside COAST_MISS
+    fn coasting_course(&self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2, target_acceleration: Vec2) -> Option<InterceptPlan> {
+        let steps = (self.max_flight_time / TIME_STEP).ceil() as u32;
+        let (time, gap) = (1..=steps)
+            .map(|step| {
+                let time = step as f64 * TIME_STEP;
+                let gap = target + target_velocity * time + target_acceleration * (0.5 * time * time) - (missile + missile_velocity * time);
+                (time, gap)
+            })
+            .min_by(|a, b| a.1.length().total_cmp(&b.1.length()))?;
+        if gap.length() > COAST_MISS {
+            return None;
+        }
+        Some(InterceptPlan {
+            time,
+            delta_v: self.delta_v(gap.length(), time)?,
+            direction: burn_direction(gap),
+        })
+    }
+
+    // Cheapest burn that covers `distance` in `time`, None if even a full burn cannot
+    fn delta_v(&self, distance: f64, time: f64) -> Option<f64> {
+        let a = self.max_acceleration;
+        let discriminant = a * a * time * time - 2.0 * a * distance;
+        (a > 0.0 && discriminant >= 0.0).then(|| a * time - discriminant.sqrt())
+    }
+}
@@ -0,0 +1,95 @@
+/*******************************************************************
+* ** Proximity Fuse **
+* Predicts the closest point of approach from the relative state
+* of missile and target and fires the warhead on the tick that
+* gets nearest to it. Damage falls off across the blast radius,
+* so inside it the best tick is always the closest one: the fuse
+* goes off once the target is in range and the next tick would
+* find it further away. The warhead goes off after the tick's
+* move, so every distance is judged FUSE_DELAY ahead
+********************************************************************/
+use crate::constants::{MISSILE_BLAST_RADIUS, TICKS_PER_SECOND};
+use oort_api::prelude::*;
+
+pub const FUSE_DELAY: f64 = 1.0 / TICKS_PER_SECOND; // seconds from explode() to detonation
+pub const ARMING_TIME: f64 = 0.5; // seconds before the closest approach the fuse takes over
+
+/*****************************************************
+* ClosestApproach
+* When and how near two bodies on straight courses
+* pass. A pair already moving apart is at its
+* closest now
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClosestApproach {
+    pub time: f64, // seconds from now, never negative
+    pub miss: f64, // meters
+}
+
+impl ClosestApproach {
+    pub fn create(relative_position: Vec2, relative_velocity: Vec2) -> ClosestApproach {
+        let speed_squared = relative_velocity.dot(relative_velocity);
+        let time = if speed_squared > 0.0 {
+            (-relative_position.dot(relative_velocity) / speed_squared).max(0.0)
+        } else {
+            0.0
+        };
+        ClosestApproach {
+            time,
+            miss: (relative_position + relative_velocity * time).length(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProximityFuse {
+    pub blast_radius: f64,
+    pub delay: f64,
+    pub arming_time: f64,
+    pub approach: Option<ClosestApproach>, // the last prediction, for diagnostics
+}
+
+impl ProximityFuse {
+    pub fn create() -> ProximityFuse {
+        ProximityFuse {
+            blast_radius: MISSILE_BLAST_RADIUS,
+            delay: FUSE_DELAY,
+            arming_time: ARMING_TIME,
+            approach: None,
+        }
+    }
+
+    pub fn with_blast_radius(mut self, blast_radius: f64) -> ProximityFuse {
+        self.blast_radius = blast_radius;
+        self
+    }
+
+    pub fn with_delay(mut self, delay: f64) -> ProximityFuse {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_arming_time(mut self, arming_time: f64) -> ProximityFuse {
+        self.arming_time = arming_time;
+        self
+    }
+
+    // Predict the pass and decide whether to detonate this tick
+    pub fn update(&mut self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2) -> bool {
+        let (relative_position, relative_velocity) = (target - missile, target_velocity - missile_velocity);
+        self.approach = Some(ClosestApproach::create(relative_position, relative_velocity));
+        let distance_at = |time: f64| (relative_position + relative_velocity * time).length();
+        let this_tick = distance_at(self.delay);
+        this_tick <= self.blast_radius && distance_at(self.delay + 1.0 / TICKS_PER_SECOND) >= this_tick
+    }
+
+    // Armed once the last prediction has the pass closing and due within arming_time. A
+    // pair already opening is not, the missile is behind its target and still chasing
+    pub fn is_armed(&self) -> bool {
+        self.approach.is_some_and(|approach| approach.time > 0.0 && approach.time <= self.arming_time)
+    }
+
+    pub fn predicted_miss(&self) -> Option<f64> {
+        self.approach.map(|approach| approach.miss)
+    }
+}
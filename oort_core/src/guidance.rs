@@ -0,0 +1,122 @@
+/*******************************************************************
+* ** Guidance **
+* Proportional navigation for missiles. The line of sight to the
+* target turns at rate w, and PN commands an acceleration of N
+* times that rate across it, which drives w to zero and puts the
+* missile on a collision course:
+*   pure PN:      N * w * |Vm|, across the missile velocity
+*   true PN:      N * w * Vc,   across the line of sight
+*   augmented PN: true PN plus N/2 of the target acceleration
+*                 across the line of sight
+* Vm is the missile velocity and Vc the closing speed. Every
+* command is limited to the missile's max acceleration
+********************************************************************/
+use crate::constants::MISSILE_LOS_TUNE_FACTOR;
+use oort_api::prelude::*;
+
+pub const DEFAULT_MAX_ACCELERATION: f64 = 300.0; // m/s^2, a missile's forward thrust
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PnLaw {
+    Pure,
+    True,
+    Augmented,
+}
+
+// Line of sight from missile to target, and how it is turning
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineOfSight {
+    pub direction: Vec2,    // unit vector toward the target
+    pub range: f64,         // meters
+    pub rate: f64,          // rad/s, positive counter clockwise
+    pub closing_speed: f64, // m/s, positive while closing
+}
+
+impl LineOfSight {
+    pub fn create(relative_position: Vec2, relative_velocity: Vec2) -> Option<LineOfSight> {
+        let range = relative_position.length();
+        if range < f64::EPSILON {
+            return None;
+        }
+        Some(LineOfSight {
+            direction: relative_position / range,
+            range,
+            rate: relative_position.cross(relative_velocity) / (range * range),
+            closing_speed: -relative_position.dot(relative_velocity) / range,
+        })
+    }
+}
+
+/*****************************************************
+* Guidance
+* A PN law with its navigation constant and the
+* acceleration the missile can pull
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Guidance {
+    pub law: PnLaw,
+    pub navigation_constant: f64,
+    pub max_acceleration: f64,
+}
+
+impl Guidance {
+    pub fn create(law: PnLaw) -> Guidance {
+        Guidance {
+            law,
+            navigation_constant: MISSILE_LOS_TUNE_FACTOR,
+            max_acceleration: DEFAULT_MAX_ACCELERATION,
+        }
+    }
+
+    pub fn with_navigation_constant(mut self, navigation_constant: f64) -> Guidance {
+        self.navigation_constant = navigation_constant;
+        self
+    }
+
+    pub fn with_max_acceleration(mut self, max_acceleration: f64) -> Guidance {
+        self.max_acceleration = max_acceleration;
+        self
+    }
+
+    // The PN acceleration, limited to max_acceleration
+    pub fn command(&self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2, target_acceleration: Vec2) -> Vec2 {
+        let Some(sight) = LineOfSight::create(target - missile, target_velocity - missile_velocity) else {
+            return vec2(0.0, 0.0);
+        };
+        let n = self.navigation_constant;
+        let across_sight = perpendicular(sight.direction);
+        let command = match self.law {
+            PnLaw::Pure => perpendicular(missile_velocity) * (n * sight.rate),
+            PnLaw::True => across_sight * (n * sight.rate * sight.closing_speed),
+            PnLaw::Augmented => {
+                let target_across = across_sight * target_acceleration.dot(across_sight);
+                across_sight * (n * sight.rate * sight.closing_speed) + target_across * (n / 2.0)
+            }
+        };
+        limit(command, self.max_acceleration)
+    }
+
+    // The PN command with whatever acceleration is left spent closing along the line of sight
+    pub fn thrust(&self, missile: Vec2, missile_velocity: Vec2, target: Vec2, target_velocity: Vec2, target_acceleration: Vec2) -> Vec2 {
+        let command = self.command(missile, missile_velocity, target, target_velocity, target_acceleration);
+        let Some(sight) = LineOfSight::create(target - missile, target_velocity - missile_velocity) else {
+            return command;
+        };
+        let spare = (self.max_acceleration * self.max_acceleration - command.dot(command)).max(0.0).sqrt();
+        command + sight.direction * spare
+    }
+}
+
+// Rotated a quarter turn counter clockwise
+fn perpendicular(v: Vec2) -> Vec2 {
+    vec2(-v.y, v.x)
+}
+
+fn limit(v: Vec2, max: f64) -> Vec2 {
+    let length = v.length();
+    if length > max && length > 0.0 {
+        v * (max / length)
+    } else {
+        v
+    }
+}
@@ -0,0 +1,128 @@
+/*******************************************************************
+* ** Intercept **
+* Closed-form time of flight for a projectile fired at a target
+* moving at constant velocity. Solves |p + v t| = s t for the
+* earliest positive t, where p and v are the target's position
+* and velocity relative to the shooter. solve_intercept refines
+* that answer for accelerating targets and real projectiles
+********************************************************************/
+use crate::constants::{BULLET_LIFETIME, BULLET_SPEED};
+use oort_api::prelude::*;
+
+const EPSILON: f64 = 1e-9;
+
+// Seconds until a projectile at projectile_speed meets the target, None when it never can
+pub fn intercept_time(relative_position: Vec2, relative_velocity: Vec2, projectile_speed: f64) -> Option<f64> {
+    // (v.v - s^2) t^2 + 2 (p.v) t + p.p = 0
+    let a = relative_velocity.dot(relative_velocity) - projectile_speed * projectile_speed;
+    let b = 2.0 * relative_position.dot(relative_velocity);
+    let c = relative_position.dot(relative_position);
+    if a.abs() < EPSILON {
+        // target closes exactly as fast as the projectile, the equation is linear
+        if b.abs() < EPSILON {
+            return None;
+        }
+        let time = -c / b;
+        return (time > 0.0).then_some(time);
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let (first, second) = ((-b - root) / (2.0 * a), (-b + root) / (2.0 * a));
+    match (first.min(second), first.max(second)) {
+        (earliest, _) if earliest > 0.0 => Some(earliest),
+        (_, latest) if latest > 0.0 => Some(latest),
+        _ => None,
+    }
+}
+
+// Where to aim so a projectile fired now meets the target, None when it never can
+pub fn intercept_point(shooter: Vec2, shooter_velocity: Vec2, target: Vec2, target_velocity: Vec2, projectile_speed: f64) -> Option<Vec2> {
+    let relative_velocity = target_velocity - shooter_velocity;
+    intercept_time(target - shooter, relative_velocity, projectile_speed).map(|time| target + relative_velocity * time)
+}
+
+/*****************************************************
+* Projectile
+* What leaves the barrel: muzzle speed, whether it
+* keeps the shooter's velocity, and how long it flies
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Projectile {
+    pub speed: f64,
+    pub inherits_velocity: bool,
+    pub lifetime: Option<f64>, // seconds, None flies forever
+}
+
+impl Projectile {
+    pub fn create(speed: f64) -> Projectile {
+        Projectile {
+            speed,
+            inherits_velocity: true,
+            lifetime: None,
+        }
+    }
+
+    // The fighter gun round
+    pub fn bullet() -> Projectile {
+        Projectile::create(BULLET_SPEED).with_lifetime(BULLET_LIFETIME)
+    }
+
+    pub fn with_lifetime(mut self, lifetime: f64) -> Projectile {
+        self.lifetime = Some(lifetime);
+        self
+    }
+
+    // For self propelled projectiles that close at `speed` whatever the shooter was doing
+    pub fn without_inherited_velocity(mut self) -> Projectile {
+        self.inherits_velocity = false;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterceptSolution {
+    pub aim_point: Vec2,
+    pub time: f64,          // seconds of flight
+    pub miss_distance: f64, // meters left between projectile and target at `time`
+}
+
+const SOLVER_ITERATIONS: usize = 8;
+const SOLVER_TOLERANCE: f64 = 1e-4; // seconds
+
+// Aim point for a target under constant acceleration. Starts from the constant velocity
+// answer and re-solves the flight time against where the acceleration has carried the
+// target. A solution past the projectile lifetime still aims, but its miss distance is
+// how far short the projectile falls when it expires
+pub fn solve_intercept(
+    shooter: Vec2,
+    shooter_velocity: Vec2,
+    target: Vec2,
+    target_velocity: Vec2,
+    target_acceleration: Vec2,
+    projectile: &Projectile,
+) -> Option<InterceptSolution> {
+    let carried = if projectile.inherits_velocity { shooter_velocity } else { vec2(0.0, 0.0) };
+    // target relative to where the projectile would be with no muzzle velocity
+    let offset_at = |time: f64| target - shooter + (target_velocity - carried) * time + target_acceleration * (0.5 * time * time);
+    let mut time = intercept_time(target - shooter, target_velocity - carried, projectile.speed)?;
+    for _ in 0..SOLVER_ITERATIONS {
+        let next = offset_at(time).length() / projectile.speed;
+        let settled = (next - time).abs() < SOLVER_TOLERANCE;
+        time = next;
+        if settled {
+            break;
+        }
+    }
+    if !time.is_finite() {
+        return None;
+    }
+    let flight = projectile.lifetime.map_or(time, |lifetime| time.min(lifetime));
+    Some(InterceptSolution {
+        aim_point: shooter + offset_at(time),
+        time,
+        miss_distance: (offset_at(flight).length() - projectile.speed * flight).abs(),
+    })
+}
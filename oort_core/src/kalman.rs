@@ -0,0 +1,228 @@
+/*******************************************************************
+* ** Kalman **
+* Constant-acceleration Kalman filter for a single contact. The x
+* and y axes are independent, so each runs its own three state
+* filter [position, velocity, acceleration] against the position
+* and velocity a scan reports. Measurement noise follows the
+* radar: error grows with range and beam width and shrinks with
+* signal strength
+********************************************************************/
+use oort_api::prelude::*;
+
+pub const DEFAULT_JERK_NOISE: f64 = 100.0; // (m/s^3)^2 per second, how hard targets are expected to jink
+pub const INITIAL_ACCELERATION_SIGMA: f64 = 60.0; // m/s^2
+pub const MINIMUM_POSITION_SIGMA: f64 = 0.5; // meters, keeps perfect measurements from collapsing the filter
+
+type Matrix = [[f64; 3]; 3];
+
+// One sigma of measurement error for a scan
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasurementNoise {
+    pub position: f64, // meters
+    pub velocity: f64, // m/s
+}
+
+impl MeasurementNoise {
+    pub fn create(position: f64, velocity: f64) -> MeasurementNoise {
+        MeasurementNoise { position, velocity }
+    }
+
+    // Radar error at `range` with a beam `width` radians wide and `snr` dB of signal
+    pub fn from_snr(range: f64, width: f64, snr: f64) -> MeasurementNoise {
+        let position = (range * width * 10f64.powf(-snr.max(0.0) / 20.0)).max(MINIMUM_POSITION_SIGMA);
+        MeasurementNoise::create(position, position / 2.0)
+    }
+
+    // For truth sources such as target() and radio relays
+    pub fn exact() -> MeasurementNoise {
+        MeasurementNoise::create(MINIMUM_POSITION_SIGMA, MINIMUM_POSITION_SIGMA / 2.0)
+    }
+}
+
+/*****************************************************
+* AxisFilter
+* [position, velocity, acceleration] along one axis
+* and its covariance
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisFilter {
+    pub state: [f64; 3],
+    pub covariance: Matrix,
+}
+
+impl AxisFilter {
+    fn create(position: f64, velocity: f64, noise: MeasurementNoise) -> AxisFilter {
+        let mut covariance = [[0.0; 3]; 3];
+        covariance[0][0] = noise.position * noise.position;
+        covariance[1][1] = noise.velocity * noise.velocity;
+        covariance[2][2] = INITIAL_ACCELERATION_SIGMA * INITIAL_ACCELERATION_SIGMA;
+        AxisFilter {
+            state: [position, velocity, 0.0],
+            covariance,
+        }
+    }
+
+    fn predict(&mut self, dt: f64, jerk_noise: f64) {
+        let transition = [[1.0, dt, dt * dt / 2.0], [0.0, 1.0, dt], [0.0, 0.0, 1.0]];
+        let [p, v, a] = self.state;
+        self.state = [p + v * dt + a * dt * dt / 2.0, v + a * dt, a];
+        // continuous white jerk
+        let (dt2, dt3) = (dt * dt, dt * dt * dt);
+        let process = [
+            [dt3 * dt2 / 20.0, dt2 * dt2 / 8.0, dt3 / 6.0],
+            [dt2 * dt2 / 8.0, dt3 / 3.0, dt2 / 2.0],
+            [dt3 / 6.0, dt2 / 2.0, dt],
+        ];
+        let mut covariance = multiply(&multiply(&transition, &self.covariance), &transpose(&transition));
+        for (row, process_row) in covariance.iter_mut().zip(process.iter()) {
+            for (value, process_value) in row.iter_mut().zip(process_row.iter()) {
+                *value += process_value * jerk_noise;
+            }
+        }
+        self.covariance = covariance;
+    }
+
+    fn update(&mut self, position: f64, velocity: f64, noise: MeasurementNoise) {
+        let p = self.covariance;
+        // innovation covariance S = H P H' + R, H picks position and velocity
+        let s = [
+            [p[0][0] + noise.position * noise.position, p[0][1]],
+            [p[1][0], p[1][1] + noise.velocity * noise.velocity],
+        ];
+        let determinant = s[0][0] * s[1][1] - s[0][1] * s[1][0];
+        if determinant.abs() < f64::EPSILON {
+            return;
+        }
+        let inverse = [[s[1][1] / determinant, -s[0][1] / determinant], [-s[1][0] / determinant, s[0][0] / determinant]];
+        // gain K = P H' S^-1
+        let mut gain = [[0.0; 2]; 3];
+        for (row, gain_row) in gain.iter_mut().enumerate() {
+            for (column, value) in gain_row.iter_mut().enumerate() {
+                *value = p[row][0] * inverse[0][column] + p[row][1] * inverse[1][column];
+            }
+        }
+        let innovation = [position - self.state[0], velocity - self.state[1]];
+        for (row, value) in self.state.iter_mut().enumerate() {
+            *value += gain[row][0] * innovation[0] + gain[row][1] * innovation[1];
+        }
+        // P = (I - K H) P
+        let mut covariance = p;
+        for (row, covariance_row) in covariance.iter_mut().enumerate() {
+            for (column, value) in covariance_row.iter_mut().enumerate() {
+                *value -= gain[row][0] * p[0][column] + gain[row][1] * p[1][column];
+            }
+        }
+        self.covariance = covariance;
+    }
+}
+
+fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut result = [[0.0; 3]; 3];
+    for (row, result_row) in result.iter_mut().enumerate() {
+        for (column, value) in result_row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[row][k] * b[k][column]).sum();
+        }
+    }
+    result
+}
+
+fn transpose(a: &Matrix) -> Matrix {
+    let mut result = [[0.0; 3]; 3];
+    for (row, result_row) in result.iter_mut().enumerate() {
+        for (column, value) in result_row.iter_mut().enumerate() {
+            *value = a[column][row];
+        }
+    }
+    result
+}
+
+/*****************************************************
+* ContactFilter
+* Smoothed position, velocity and acceleration for
+* one contact, predicted forward to each measurement
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContactFilter {
+    pub x: AxisFilter,
+    pub y: AxisFilter,
+    pub time: f64, // seconds, when the state is valid
+    pub jerk_noise: f64,
+}
+
+impl ContactFilter {
+    pub fn create(position: Vec2, velocity: Vec2, noise: MeasurementNoise, time: f64) -> ContactFilter {
+        ContactFilter {
+            x: AxisFilter::create(position.x, velocity.x, noise),
+            y: AxisFilter::create(position.y, velocity.y, noise),
+            time,
+            jerk_noise: DEFAULT_JERK_NOISE,
+        }
+    }
+
+    // Start from a scan taken from our current position with the current radar width
+    pub fn from_scan(contact: &ScanResult) -> ContactFilter {
+        ContactFilter::create(contact.position, contact.velocity, scan_noise(contact), current_time())
+    }
+
+    pub fn with_jerk_noise(mut self, jerk_noise: f64) -> ContactFilter {
+        self.jerk_noise = jerk_noise;
+        self
+    }
+
+    // Advance the state to `time`, earlier times are ignored
+    pub fn predict(&mut self, time: f64) {
+        let dt = time - self.time;
+        if dt <= 0.0 {
+            return;
+        }
+        self.x.predict(dt, self.jerk_noise);
+        self.y.predict(dt, self.jerk_noise);
+        self.time = time;
+    }
+
+    pub fn update(&mut self, position: Vec2, velocity: Vec2, noise: MeasurementNoise, time: f64) {
+        self.predict(time);
+        self.x.update(position.x, velocity.x, noise);
+        self.y.update(position.y, velocity.y, noise);
+    }
+
+    // Fold in a scan taken this tick
+    pub fn ingest(&mut self, contact: &ScanResult) {
+        self.update(contact.position, contact.velocity, scan_noise(contact), current_time());
+    }
+
+    pub fn position(&self) -> Vec2 {
+        vec2(self.x.state[0], self.y.state[0])
+    }
+
+    pub fn velocity(&self) -> Vec2 {
+        vec2(self.x.state[1], self.y.state[1])
+    }
+
+    pub fn acceleration(&self) -> Vec2 {
+        vec2(self.x.state[2], self.y.state[2])
+    }
+
+    // Per axis variance of position, velocity and acceleration
+    pub fn position_variance(&self) -> Vec2 {
+        vec2(self.x.covariance[0][0], self.y.covariance[0][0])
+    }
+
+    pub fn velocity_variance(&self) -> Vec2 {
+        vec2(self.x.covariance[1][1], self.y.covariance[1][1])
+    }
+
+    pub fn acceleration_variance(&self) -> Vec2 {
+        vec2(self.x.covariance[2][2], self.y.covariance[2][2])
+    }
+
+    // Where the contact will be `dt` seconds after the filter time
+    pub fn extrapolate(&self, dt: f64) -> Vec2 {
+        self.position() + self.velocity() * dt + self.acceleration() * (0.5 * dt * dt)
+    }
+}
+
+// Measurement noise for a scan taken from our current position with the current radar width
+pub fn scan_noise(contact: &ScanResult) -> MeasurementNoise {
+    MeasurementNoise::from_snr((contact.position - position()).length(), radar_width(), contact.snr)
+}
@@ -0,0 +1,25 @@
+/**************************************************************
+* oort_core
+* Author: Christopher Dean
+* Shared ship subsystems for the oort.rs tutorial solutions.
+* Every tutorial Ship composes these modules instead of
+* re-pasting them. Modules may only depend on oort_api and
+* on each other through `crate::`, so the bundler can inline
+* them into a single submission file.
+****************************************************************/
+pub mod blackboard;
+pub mod constants;
+pub mod coordination;
+pub mod diagnostics;
+pub mod envelope;
+pub mod fuse;
+pub mod guidance;
+pub mod intercept;
+pub mod kalman;
+pub mod navigation;
+pub mod radar;
+pub mod radio;
+pub mod targeting;
+pub mod tracking;
+pub mod turning;
+pub mod weapons;
@@ -0,0 +1,192 @@
+/*******************************************************************************************************************
+* ** Navigation System **
+* Handles navigating and calculating the next thruster vectors based on the target_position
+* The navigation system also helps to steer heading to target_heading
+********************************************************************************************************************/
+use crate::constants::{BULLET_SPEED, TICKS_PER_SECOND};
+use crate::intercept::intercept_time;
+use crate::turning::braking_speed;
+use oort_api::prelude::*;
+
+const HOMING_GAIN: f64 = 1.0; // m/s^2 of thrust per meter of predicted miss
+
+// Closes in from beyond orbit_max_distance and stops at orbit_min_distance, backs off to it when
+// too close and holds whatever range it has in between, all while matching the target's velocity
+pub fn approach_and_orbit(orbit_min_distance: f64, orbit_max_distance: f64, position: Vec2, target_position: Vec2, target_velocity: Vec2) -> Vec2 {
+    let station = orbit_station(orbit_min_distance, orbit_max_distance, position, target_position);
+    MotionPlanner::for_ship().acceleration(position, velocity(), heading(), station, target_velocity)
+}
+
+// approach_and_orbit for ships that aim their nose at something else on the way in: every
+// engine pushes as hard as it can while closing, so the run in is not held to the lateral limit
+pub fn dash_and_orbit(orbit_min_distance: f64, orbit_max_distance: f64, position: Vec2, target_position: Vec2, target_velocity: Vec2) -> Vec2 {
+    let station = orbit_station(orbit_min_distance, orbit_max_distance, position, target_position);
+    MotionPlanner::for_ship()
+        .with_full_thrust()
+        .acceleration(position, velocity(), heading(), station, target_velocity)
+}
+
+// approach_and_orbit on a spring: the closing speed is held to approach_gain times the
+// distance left, so the ship eases onto its station instead of braking hard at the end
+pub fn spring_orbit(
+    approach_gain: f64,
+    orbit_min_distance: f64,
+    orbit_max_distance: f64,
+    position: Vec2,
+    target_position: Vec2,
+    target_velocity: Vec2,
+) -> Vec2 {
+    let station = orbit_station(orbit_min_distance, orbit_max_distance, position, target_position);
+    MotionPlanner::for_ship()
+        .with_max_speed(approach_gain * (station - position).length())
+        .acceleration(position, velocity(), heading(), station, target_velocity)
+}
+
+// Where to sit: orbit_min_distance out along the current bearing when outside the band,
+// where we are when inside it. A ship on top of the target stays put
+fn orbit_station(orbit_min_distance: f64, orbit_max_distance: f64, position: Vec2, target_position: Vec2) -> Vec2 {
+    let offset = position - target_position;
+    let range = offset.length();
+    if range > 0.0 && (range > orbit_max_distance || range < orbit_min_distance) {
+        target_position + offset / range * orbit_min_distance
+    } else {
+        position
+    }
+}
+
+/*****************************************************
+* ThrustLimits
+* How hard the engines push forward, backward and to
+* the side, relative to the heading
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThrustLimits {
+    pub forward: f64,
+    pub backward: f64,
+    pub lateral: f64,
+}
+
+impl ThrustLimits {
+    pub fn create(forward: f64, backward: f64, lateral: f64) -> ThrustLimits {
+        ThrustLimits { forward, backward, lateral }
+    }
+
+    // This ship's engines
+    pub fn current() -> ThrustLimits {
+        ThrustLimits::create(max_forward_acceleration(), max_backward_acceleration(), max_lateral_acceleration())
+    }
+
+    // The acceleration every direction can get whichever way the ship faces
+    pub fn weakest(&self) -> f64 {
+        self.forward.min(self.backward).min(self.lateral)
+    }
+
+    // Scales a world frame acceleration down until it fits the limits at `heading`. The
+    // engines would clip each axis on their own and bend the command, this keeps its direction
+    pub fn clamp(&self, acceleration: Vec2, heading: f64) -> Vec2 {
+        let local = acceleration.rotate(-heading);
+        let mut scale: f64 = 1.0;
+        if local.x > self.forward {
+            scale = scale.min(self.forward / local.x);
+        }
+        if -local.x > self.backward {
+            scale = scale.min(self.backward / -local.x);
+        }
+        if local.y.abs() > self.lateral {
+            scale = scale.min(self.lateral / local.y.abs());
+        }
+        acceleration * scale
+    }
+
+    // Clips each axis on its own, as the engines do. For commands that want every bit of
+    // thrust toward a side more than they want their exact direction
+    pub fn clip(&self, acceleration: Vec2, heading: f64) -> Vec2 {
+        let local = acceleration.rotate(-heading);
+        vec2(local.x.clamp(-self.backward, self.forward), local.y.clamp(-self.lateral, self.lateral)).rotate(heading)
+    }
+}
+
+/*****************************************************
+* MotionPlanner
+* Drives the ship onto a goal position moving at a
+* goal velocity. Closing speed follows the discrete
+* braking curve of the weakest engine, so the ship
+* still stops in time if it turns while braking, and
+* any sideways drift is nulled on the way in. Every
+* command fits the thrust limits at the heading, with
+* its direction kept unless full thrust is asked for
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MotionPlanner {
+    pub limits: ThrustLimits,
+    pub max_speed: f64,    // m/s closing on the goal
+    pub full_thrust: bool, // clip each axis while closing instead of keeping the direction
+}
+
+impl MotionPlanner {
+    pub fn create(limits: ThrustLimits) -> MotionPlanner {
+        MotionPlanner {
+            limits,
+            max_speed: f64::INFINITY,
+            full_thrust: false,
+        }
+    }
+
+    pub fn for_ship() -> MotionPlanner {
+        MotionPlanner::create(ThrustLimits::current())
+    }
+
+    pub fn with_max_speed(mut self, max_speed: f64) -> MotionPlanner {
+        self.max_speed = max_speed;
+        self
+    }
+
+    pub fn with_full_thrust(mut self) -> MotionPlanner {
+        self.full_thrust = true;
+        self
+    }
+
+    // Acceleration for this tick toward the goal
+    pub fn acceleration(&self, position: Vec2, velocity: Vec2, heading: f64, goal_position: Vec2, goal_velocity: Vec2) -> Vec2 {
+        let offset = goal_position - position;
+        let distance = offset.length();
+        let wanted_velocity = if distance > 0.0 {
+            goal_velocity + offset / distance * braking_speed(distance, self.limits.weakest()).min(self.max_speed)
+        } else {
+            goal_velocity
+        };
+        let command = (wanted_velocity - velocity) * TICKS_PER_SECOND;
+        // Braking stays on clamp so the stop lands where the curve says it will
+        if self.full_thrust && command.dot(offset) > 0.0 {
+            self.limits.clip(command, heading)
+        } else {
+            self.limits.clamp(command, heading)
+        }
+    }
+
+    // acceleration() for this ship
+    pub fn arrive(&self, goal_position: Vec2, goal_velocity: Vec2) -> Vec2 {
+        self.acceleration(position(), velocity(), heading(), goal_position, goal_velocity)
+    }
+}
+
+/********************************************************************************************************************
+* ** Engine Thrust and Drive System **
+* This code is responsible for updating the ships next torque and accelerate values
+*******************************************************************************************************************/
+pub fn update_engine_vectors(next_torque: f64, acceleration: Option<Vec2>) {
+    // Update Angular Velocity
+    torque(next_torque);
+    // Update Planar Thrust Vectors, kept in the thrust limits without bending the command
+    accelerate(ThrustLimits::current().clamp(acceleration.unwrap_or(vec2(0.0, 0.0)), heading()));
+}
+
+// Missile homing used by the Missiles and Squadron solutions: accelerates against the predicted
+// miss, leading by the intercept time at bullet speed or by the straight line time when there
+// is no intercept. Far out every engine saturates toward the target
+pub fn missile_homing(line_diff: Vec2, target_velocity: Vec2) -> Vec2 {
+    let relative_velocity = target_velocity - velocity();
+    let time = intercept_time(line_diff, relative_velocity, BULLET_SPEED).unwrap_or(line_diff.length() / BULLET_SPEED);
+    let miss = line_diff + relative_velocity * time;
+    ThrustLimits::current().clip(miss * HOMING_GAIN, heading())
+}
@@ -0,0 +1,377 @@
+/*******************************************************************
+* ** Radar and Enemy Tracking **
+* This code is responsible for pointing the radar and collecting
+* the contacts it reports
+********************************************************************/
+use crate::constants::TICKS_PER_SECOND;
+use crate::tracking::{Track, TrackId, TrackTable};
+use oort_api::prelude::*;
+use std::collections::VecDeque;
+
+pub const SWEEP_STEP: f64 = 0.0628; // radians per tick while searching
+pub const MIN_BEAM_WIDTH: f64 = TAU / 720.0;
+pub const URGENT_REVISIT: f64 = 4.0; // times the revisit threshold, beats point of interest dwells
+
+/*****************************************************
+* SweepRadar
+* Rotates the radar until something is found, then
+* holds the beam on the requested lock heading
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepRadar {
+    pub step: f64,
+    pub sweep_max_distance: Option<f64>,
+    pub class_filter: Option<Class>,
+    pub contact: Option<ScanResult>,
+}
+
+impl SweepRadar {
+    pub fn create(step: f64) -> SweepRadar {
+        SweepRadar {
+            step,
+            sweep_max_distance: None,
+            class_filter: None,
+            contact: None,
+        }
+    }
+
+    // Limit the radar range while sweeping
+    pub fn with_sweep_distance(mut self, max_distance: f64) -> SweepRadar {
+        self.sweep_max_distance = Some(max_distance);
+        self
+    }
+
+    // Ignore contacts that are not of this class
+    pub fn with_class_filter(mut self, class: Class) -> SweepRadar {
+        self.class_filter = Some(class);
+        self
+    }
+
+    // Seek
+    pub fn update(&mut self, lock_heading: Option<f64>) {
+        if self.contact.is_none() {
+            set_radar_heading(radar_heading() + self.step);
+            if let Some(max_distance) = self.sweep_max_distance {
+                set_radar_max_distance(max_distance);
+            }
+            self.seek();
+        } else if let Some(lock_heading) = lock_heading {
+            set_radar_heading(radar_heading() + angle_diff(radar_heading(), lock_heading));
+            self.seek();
+        }
+    }
+
+    // Look at `heading` whatever we are locked on to, for targets handed to us
+    pub fn cue(&mut self, heading: f64) {
+        set_radar_heading(heading);
+        self.seek();
+    }
+
+    pub fn seek(&mut self) {
+        self.contact = scan().filter(|result| match self.class_filter {
+            Some(class) => result.class == class,
+            None => true,
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RadarState {
+    Initialize = 0,
+    BroadScan = 1,
+    NarrowScan = 2,
+    POIScan = 3,
+    NoneState,
+}
+
+/*****************************************************
+* RadarData
+* State machine used by the Castle Missiles ships,
+* broad sweeps until a contact is handed over, then
+* narrows the beam around the contact
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadarData {
+    pub step_size: f64,
+    pub last_state_heading: Option<f64>,
+    pub next_heading: Option<f64>,
+    pub radar_state: RadarState,
+    pub previous_state: RadarState,
+    pub tick_counter: u64,
+}
+
+impl RadarData {
+    pub fn create() -> RadarData {
+        RadarData {
+            step_size: -0.0678,
+            last_state_heading: None,
+            radar_state: RadarState::Initialize,
+            previous_state: RadarState::NoneState,
+            next_heading: None,
+            tick_counter: 0,
+        }
+    }
+
+    pub fn update_broad_scan(&mut self) {
+        self.last_state_heading = Some(radar_heading());
+        set_radar_width(TAU / 16.0);
+        let next_heading = radar_heading() + self.step_size;
+        self.next_heading = Some(next_heading);
+        set_radar_heading(next_heading);
+        set_radar_max_distance(world_size() / 4.0);
+    }
+
+    pub fn update_narrow_scan(&mut self, position: Vec2, velocity: Vec2) {
+        self.previous_state = self.radar_state;
+        self.last_state_heading = Some(radar_heading());
+        set_radar_width(TAU / 16.0);
+        let mut distance_scaler = 1.0;
+        let distance = position - oort_api::prelude::position();
+        let mut radar_length = distance.length();
+        if radar_length < 200.0 {
+            distance_scaler = 120.0 / radar_length;
+            radar_length = 200.0;
+            set_radar_width(TAU / 12.0);
+        }
+        let next_heading = (position + (velocity / TICKS_PER_SECOND * distance_scaler) - oort_api::prelude::position()).angle();
+        self.next_heading = Some(next_heading);
+        set_radar_heading(next_heading);
+        set_radar_max_distance(radar_length * 1.335);
+        self.radar_state = RadarState::NarrowScan;
+    }
+
+    // Dwell on a point the contact is believed to be near, with the beam opened
+    // just wide enough to cover `uncertainty` meters around it
+    pub fn update_poi_scan(&mut self, point: Vec2, uncertainty: f64) {
+        if self.radar_state != RadarState::POIScan {
+            self.previous_state = self.radar_state;
+            self.tick_counter = 0;
+        }
+        self.last_state_heading = Some(radar_heading());
+        let look = beam_on(point, uncertainty, MIN_BEAM_WIDTH, TAU / 16.0);
+        self.next_heading = Some(look.heading);
+        look.apply();
+        self.radar_state = RadarState::POIScan;
+        self.tick_counter += 1;
+    }
+
+    // True once a POI dwell has run for `ticks` without finding anything
+    pub fn poi_expired(&self, ticks: u64) -> bool {
+        self.radar_state == RadarState::POIScan && self.tick_counter >= ticks
+    }
+
+    pub fn initialize(&mut self) {
+        self.previous_state = RadarState::Initialize;
+        self.radar_state = RadarState::BroadScan;
+    }
+}
+
+/*****************************************************
+* Look
+* One tick's radar pointing
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Look {
+    pub heading: f64,
+    pub width: f64,
+    pub min_distance: f64,
+    pub max_distance: f64,
+}
+
+impl Look {
+    pub fn apply(&self) {
+        set_radar_heading(self.heading);
+        set_radar_width(self.width);
+        set_radar_min_distance(self.min_distance);
+        set_radar_max_distance(self.max_distance);
+    }
+}
+
+// A beam centred on `point` that covers `radius` meters around it
+pub fn beam_on(point: Vec2, radius: f64, min_width: f64, max_width: f64) -> Look {
+    let offset = point - position();
+    let range = offset.length().max(1.0);
+    Look {
+        heading: offset.angle(),
+        width: (2.0 * (radius / range).atan()).clamp(min_width, max_width),
+        min_distance: (range - radius).max(0.0),
+        max_distance: range + radius,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RadarTask {
+    Sweep,
+    Revisit(TrackId),
+    Dwell(Vec2),
+}
+
+// Somewhere worth a look: a radio report or where a track was last seen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointOfInterest {
+    pub position: Vec2,
+    pub radius: f64,  // meters of uncertainty
+    pub expires: f64, // current_time() after which it is forgotten
+}
+
+// Class weight for revisit priority, things that can kill us soonest first
+pub fn class_threat(class: Class) -> f64 {
+    match class {
+        Class::Missile | Class::Torpedo => 4.0,
+        Class::Fighter => 2.0,
+        Class::Frigate => 1.5,
+        _ => 1.0,
+    }
+}
+
+// How badly a track needs a revisit: predicted position error against the revisit
+// beam half-width at its range, scaled by how threatening and how close it is
+pub fn revisit_priority(track: &Track, time: f64, beam_width: f64) -> f64 {
+    let mut predicted = track.filter;
+    predicted.predict(time);
+    let variance = predicted.position_variance();
+    let offset = predicted.position() - position();
+    let range = offset.length().max(1.0);
+    let closing = -offset.dot(predicted.velocity() - velocity()) / range;
+    let threat = class_threat(track.class) * (1.0 + closing.max(0.0) / 500.0);
+    (variance.x + variance.y).sqrt() / (range * beam_width / 2.0) * threat
+}
+
+/*****************************************************
+* RadarScheduler
+* Time-slices the single radar between a sector
+* sweep, revisits of firm tracks and dwells on
+* points of interest. Every sweep_share'th tick is a
+* sweep so the sector keeps moving. The rest go to an
+* urgent revisit, then the oldest point of interest,
+* then any track due a revisit, then the sweep again
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadarScheduler {
+    pub sweep_heading: f64,
+    pub sector_start: f64,
+    pub sector_span: f64, // TAU for a full circle
+    pub sweep_width: f64,
+    pub sweep_range: f64,
+    pub sweep_share: u64,
+    pub revisit_width: f64,
+    pub revisit_threshold: f64, // priority above which a track is revisited
+    pub points_of_interest: VecDeque<PointOfInterest>,
+    pub task: RadarTask,
+    tick: u64,
+    sweep_direction: f64,
+}
+
+impl RadarScheduler {
+    pub fn create() -> RadarScheduler {
+        RadarScheduler {
+            sweep_heading: 0.0,
+            sector_start: 0.0,
+            sector_span: TAU,
+            sweep_width: TAU / 32.0,
+            sweep_range: 20000.0,
+            sweep_share: 3,
+            revisit_width: TAU / 120.0,
+            revisit_threshold: 1.0,
+            points_of_interest: VecDeque::new(),
+            task: RadarTask::Sweep,
+            tick: 0,
+            sweep_direction: 1.0,
+        }
+    }
+
+    pub fn with_sweep(mut self, width: f64, range: f64) -> RadarScheduler {
+        self.sweep_width = width;
+        self.sweep_range = range;
+        self
+    }
+
+    // Sweep back and forth over [start, start + span] instead of the full circle
+    pub fn with_sector(mut self, start: f64, span: f64) -> RadarScheduler {
+        self.sector_start = start;
+        self.sector_span = span;
+        self.sweep_heading = start;
+        self
+    }
+
+    // One tick in `share` is always a sweep
+    pub fn with_sweep_share(mut self, share: u64) -> RadarScheduler {
+        self.sweep_share = share.max(1);
+        self
+    }
+
+    pub fn add_point_of_interest(&mut self, position: Vec2, radius: f64, time_to_live: f64) {
+        self.points_of_interest.push_back(PointOfInterest {
+            position,
+            radius,
+            expires: current_time() + time_to_live,
+        });
+    }
+
+    // Feed last tick's scan to the tracks, call before plan
+    pub fn observe(&mut self, tracks: &mut TrackTable, contact: Option<ScanResult>) {
+        let time = current_time();
+        if let Some(contact) = contact.as_ref() {
+            tracks.ingest(contact);
+        }
+        if let RadarTask::Dwell(point) = self.task {
+            // found or not, a dwell is a single look
+            self.points_of_interest.retain(|poi| poi.position != point);
+        }
+        self.points_of_interest.retain(|poi| poi.expires > time);
+        tracks.update(time);
+    }
+
+    // Pick and point this tick's task, its result arrives through scan() next tick
+    pub fn plan(&mut self, tracks: &TrackTable) -> RadarTask {
+        let time = current_time();
+        self.tick += 1;
+        let forced_sweep = self.tick.is_multiple_of(self.sweep_share);
+        let revisit = tracks
+            .firm()
+            .map(|track| (track, revisit_priority(track, time, self.revisit_width)))
+            .filter(|(_, priority)| *priority >= self.revisit_threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        let urgent = revisit.filter(|(_, priority)| *priority >= self.revisit_threshold * URGENT_REVISIT);
+        self.task = if forced_sweep {
+            self.sweep()
+        } else if let Some((track, _)) = urgent {
+            self.revisit(track, time)
+        } else if let Some(poi) = self.points_of_interest.front() {
+            beam_on(poi.position, poi.radius, MIN_BEAM_WIDTH, self.sweep_width).apply();
+            RadarTask::Dwell(poi.position)
+        } else if let Some((track, _)) = revisit {
+            self.revisit(track, time)
+        } else {
+            self.sweep()
+        };
+        self.task
+    }
+
+    fn revisit(&self, track: &Track, time: f64) -> RadarTask {
+        let mut predicted = track.filter;
+        predicted.predict(time + TICK_LENGTH);
+        let variance = predicted.position_variance();
+        let radius = 3.0 * (variance.x + variance.y).sqrt();
+        beam_on(predicted.position(), radius, MIN_BEAM_WIDTH, self.sweep_width).apply();
+        RadarTask::Revisit(track.id)
+    }
+
+    fn sweep(&mut self) -> RadarTask {
+        set_radar_heading(self.sweep_heading);
+        set_radar_width(self.sweep_width);
+        set_radar_min_distance(0.0);
+        set_radar_max_distance(self.sweep_range);
+        if self.sector_span >= TAU {
+            self.sweep_heading = (self.sweep_heading + self.sweep_width) % TAU;
+        } else {
+            // bounce between the sector edges
+            let next = self.sweep_heading + self.sweep_width * self.sweep_direction;
+            if !(0.0..=self.sector_span).contains(&(next - self.sector_start).rem_euclid(TAU)) {
+                self.sweep_direction = -self.sweep_direction;
+            }
+            self.sweep_heading += self.sweep_width * self.sweep_direction;
+        }
+        RadarTask::Sweep
+    }
+}
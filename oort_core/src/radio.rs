@@ -0,0 +1,443 @@
+/******************************************************************
+* ** Radio System **
+* Contacts are shared as [position.x, position.y, velocity.x,
+* velocity.y] on the positioning channel. Ships that need more
+* than one kind of message use the typed RadioMessage codec,
+* the RadioManager to spread them over their radios and the
+* FleetPicture to share their track tables
+*******************************************************************/
+use crate::tracking::{TrackId, TrackTable};
+use oort_api::prelude::*;
+use std::collections::VecDeque;
+
+pub const POSITIONING_CHANNEL: usize = 2;
+pub const COMMAND_CHANNEL: usize = 3;
+pub const MISSILE_CHANNEL: usize = 4;
+pub const RADIO_COUNT: usize = 4; // 4 radios for frigate, 8 for a cruiser
+pub const MISSILE_RADIO: usize = RADIO_COUNT - 1; // last radio
+pub const MAX_QUEUED_MESSAGES: usize = 8; // per purpose, the oldest is dropped past this
+
+pub fn receive_contact(channel: usize) -> Option<(Vec2, Vec2)> {
+    set_radio_channel(channel);
+    receive().map(|msg| (vec2(msg[0], msg[1]), vec2(msg[2], msg[3])))
+}
+
+pub fn send_contact(channel: usize, position: Vec2, velocity: Vec2) {
+    set_radio_channel(channel);
+    send([position.x, position.y, velocity.x, velocity.y]);
+}
+
+// What a typed message means, the codec's first field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageID {
+    Contact = 1,      // a sender's track: where it is and how it moves
+    TargetUpdate = 2, // a launcher steering its missiles onto a track
+    TrackLost = 3,    // the sender dropped the track, position is the last known one
+    Position = 4,     // the sender's own position and velocity
+    Assignment = 5,   // a target for the ship whose id is in the track field
+}
+
+impl MessageID {
+    fn from_code(code: u64) -> Option<MessageID> {
+        match code {
+            1 => Some(MessageID::Contact),
+            2 => Some(MessageID::TargetUpdate),
+            3 => Some(MessageID::TrackLost),
+            4 => Some(MessageID::Position),
+            5 => Some(MessageID::Assignment),
+            _ => None,
+        }
+    }
+}
+
+/*****************************************************
+* RadioMessage
+* Packs into the four f64 slots of a Message:
+*   0: kind (5 bits) | sender (21 bits) | track (27 bits)
+*   1: position x and y, 26 bits each at 1/64 m
+*   2: velocity x and y, 26 bits each at 1/1024 m/s
+*   3: class (4 bits) | tick the message was sent on
+*      (32 bits)
+* Every slot is an integer below 2^53, so f64 holds it
+* exactly and decoding is lossless on the grid
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RadioMessage {
+    pub id: MessageID,
+    pub sender: u32,
+    pub track: TrackId,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub class: Class, // what the track is, Unknown when the sender does not say
+    pub tick: u32,
+}
+
+const KIND_BITS: u32 = 5;
+const SENDER_BITS: u32 = 21;
+const TRACK_BITS: u32 = 27;
+const AXIS_BITS: u32 = 26;
+const CLASS_BITS: u32 = 4;
+const TICK_BITS: u32 = 32;
+pub const POSITION_RESOLUTION: f64 = 1.0 / 64.0; // meters
+pub const VELOCITY_RESOLUTION: f64 = 1.0 / 1024.0; // m/s
+
+impl RadioMessage {
+    // A message from this ship, stamped with the current tick
+    pub fn create(id: MessageID, track: TrackId, position: Vec2, velocity: Vec2) -> RadioMessage {
+        RadioMessage {
+            id,
+            sender: oort_api::prelude::id(),
+            track,
+            position,
+            velocity,
+            class: Class::Unknown,
+            tick: current_tick(),
+        }
+    }
+
+    pub fn with_class(mut self, class: Class) -> RadioMessage {
+        self.class = class;
+        self
+    }
+
+    pub fn encode(&self) -> Message {
+        let header = ((self.id as u64) << (SENDER_BITS + TRACK_BITS))
+            | ((self.sender as u64 & mask(SENDER_BITS)) << TRACK_BITS)
+            | (self.track as u64 & mask(TRACK_BITS));
+        [
+            header as f64,
+            pack_pair(self.position, POSITION_RESOLUTION) as f64,
+            pack_pair(self.velocity, VELOCITY_RESOLUTION) as f64,
+            ((class_code(self.class) << TICK_BITS) | self.tick as u64) as f64,
+        ]
+    }
+
+    // None for untyped contact messages and anything else that is not ours
+    pub fn decode(message: Message) -> Option<RadioMessage> {
+        let slots = message.map(as_integer);
+        let [Some(header), Some(position), Some(velocity), Some(stamp)] = slots else {
+            return None;
+        };
+        if header >> (KIND_BITS + SENDER_BITS + TRACK_BITS) != 0 || stamp >> (CLASS_BITS + TICK_BITS) != 0 {
+            return None;
+        }
+        Some(RadioMessage {
+            id: MessageID::from_code(header >> (SENDER_BITS + TRACK_BITS))?,
+            sender: ((header >> TRACK_BITS) & mask(SENDER_BITS)) as u32,
+            track: (header & mask(TRACK_BITS)) as TrackId,
+            position: unpack_pair(position, POSITION_RESOLUTION)?,
+            velocity: unpack_pair(velocity, VELOCITY_RESOLUTION)?,
+            class: class_from_code(stamp >> TICK_BITS)?,
+            tick: (stamp & mask(TICK_BITS)) as u32,
+        })
+    }
+
+    // Where the sender's contact is now, assuming it kept its velocity
+    pub fn extrapolated_position(&self) -> Vec2 {
+        self.position + self.velocity * self.age()
+    }
+
+    // Seconds since the message was sent
+    pub fn age(&self) -> f64 {
+        current_tick().saturating_sub(self.tick) as f64 * TICK_LENGTH
+    }
+}
+
+// Unknown is 0 so untyped senders decode as Unknown
+fn class_code(class: Class) -> u64 {
+    match class {
+        Class::Unknown => 0,
+        Class::Fighter => 1,
+        Class::Frigate => 2,
+        Class::Cruiser => 3,
+        Class::Asteroid => 4,
+        Class::Target => 5,
+        Class::Missile => 6,
+        Class::Torpedo => 7,
+    }
+}
+
+fn class_from_code(code: u64) -> Option<Class> {
+    match code {
+        0 => Some(Class::Unknown),
+        1 => Some(Class::Fighter),
+        2 => Some(Class::Frigate),
+        3 => Some(Class::Cruiser),
+        4 => Some(Class::Asteroid),
+        5 => Some(Class::Target),
+        6 => Some(Class::Missile),
+        7 => Some(Class::Torpedo),
+        _ => None,
+    }
+}
+
+fn mask(bits: u32) -> u64 {
+    (1 << bits) - 1
+}
+
+fn as_integer(slot: f64) -> Option<u64> {
+    (slot >= 0.0 && slot.fract() == 0.0 && slot < (1u64 << 53) as f64).then_some(slot as u64)
+}
+
+// Offset binary so negative values pack, clamped to the representable range
+fn pack_axis(value: f64, resolution: f64) -> u64 {
+    let half = (1i64 << (AXIS_BITS - 1)) as f64;
+    ((value / resolution).round().clamp(-half, half - 1.0) + half) as u64
+}
+
+fn unpack_axis(packed: u64, resolution: f64) -> f64 {
+    (packed as i64 - (1i64 << (AXIS_BITS - 1))) as f64 * resolution
+}
+
+fn pack_pair(value: Vec2, resolution: f64) -> u64 {
+    (pack_axis(value.x, resolution) << AXIS_BITS) | pack_axis(value.y, resolution)
+}
+
+fn unpack_pair(packed: u64, resolution: f64) -> Option<Vec2> {
+    if packed >> (2 * AXIS_BITS) != 0 {
+        return None;
+    }
+    Some(vec2(
+        unpack_axis(packed >> AXIS_BITS, resolution),
+        unpack_axis(packed & mask(AXIS_BITS), resolution),
+    ))
+}
+
+pub fn send_message(channel: usize, message: &RadioMessage) {
+    set_radio_channel(channel);
+    send(message.encode());
+}
+
+pub fn receive_message(channel: usize) -> Option<RadioMessage> {
+    set_radio_channel(channel);
+    receive().and_then(RadioMessage::decode)
+}
+
+// Radios a class carries
+pub fn radio_count(class: Class) -> usize {
+    match class {
+        Class::Fighter => 2,
+        Class::Frigate => RADIO_COUNT,
+        Class::Cruiser => 8,
+        _ => 1,
+    }
+}
+
+// What a radio is used for, each purpose has its own channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RadioPurpose {
+    FleetContacts, // tracks shared between ships
+    Command,       // orders and assignments
+    MissileUplink, // launchers steering their missiles
+}
+
+impl RadioPurpose {
+    pub fn channel(self) -> usize {
+        match self {
+            RadioPurpose::FleetContacts => POSITIONING_CHANNEL,
+            RadioPurpose::Command => COMMAND_CHANNEL,
+            RadioPurpose::MissileUplink => MISSILE_CHANNEL,
+        }
+    }
+}
+
+/*****************************************************
+* RadioManager
+* Gives each purpose a radio: the missile uplink takes
+* the last radio (MISSILE_RADIO on a frigate), the rest
+* share the others round robin. A radio carrying more
+* than one purpose listens to each in turn. Sends are
+* queued, a radio transmits one message a tick and
+* keeps listening on that channel the next tick
+*
+* Call listen() at the start of a tick and transmit()
+* at the end
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct RadioManager {
+    pub assignments: Vec<(RadioPurpose, usize)>,  // purpose, radio
+    pub outbox: Vec<VecDeque<RadioMessage>>,      // per assignment
+    pub inbox: Vec<(RadioPurpose, RadioMessage)>, // heard this tick
+    pub tuned: Vec<Option<RadioPurpose>>,         // per radio, what it is listening for
+    next_listen: Vec<usize>,
+}
+
+impl RadioManager {
+    pub fn create(radios: usize, purposes: &[RadioPurpose]) -> RadioManager {
+        let radios = radios.max(1);
+        let uplink_radio = radios - 1;
+        let shared_radios = if radios > 1 && purposes.contains(&RadioPurpose::MissileUplink) {
+            radios - 1
+        } else {
+            radios
+        };
+        let mut next_shared = 0;
+        let assignments: Vec<(RadioPurpose, usize)> = purposes
+            .iter()
+            .map(|&purpose| {
+                if purpose == RadioPurpose::MissileUplink {
+                    return (purpose, uplink_radio);
+                }
+                let radio = next_shared % shared_radios;
+                next_shared += 1;
+                (purpose, radio)
+            })
+            .collect();
+        let mut manager = RadioManager {
+            outbox: vec![VecDeque::new(); assignments.len()],
+            inbox: Vec::new(),
+            tuned: vec![None; radios],
+            next_listen: vec![0; radios],
+            assignments,
+        };
+        for radio in 0..radios {
+            manager.tune(radio);
+        }
+        manager
+    }
+
+    pub fn for_class(class: Class, purposes: &[RadioPurpose]) -> RadioManager {
+        RadioManager::create(radio_count(class), purposes)
+    }
+
+    pub fn radio(&self, purpose: RadioPurpose) -> Option<usize> {
+        self.assignments.iter().find(|(assigned, _)| *assigned == purpose).map(|(_, radio)| *radio)
+    }
+
+    pub fn queue(&mut self, purpose: RadioPurpose, message: RadioMessage) {
+        if let Some(index) = self.assignments.iter().position(|(assigned, _)| *assigned == purpose) {
+            let outbox = &mut self.outbox[index];
+            if outbox.len() >= MAX_QUEUED_MESSAGES {
+                outbox.pop_front();
+            }
+            outbox.push_back(message);
+        }
+    }
+
+    pub fn queued(&self, purpose: RadioPurpose) -> usize {
+        self.assignments
+            .iter()
+            .zip(self.outbox.iter())
+            .filter(|((assigned, _), _)| *assigned == purpose)
+            .map(|(_, outbox)| outbox.len())
+            .sum()
+    }
+
+    // Latest message heard for `purpose` this tick
+    pub fn receive(&self, purpose: RadioPurpose) -> Option<RadioMessage> {
+        self.inbox.iter().rev().find(|(heard, _)| *heard == purpose).map(|(_, message)| *message)
+    }
+
+    // Read every radio into the inbox
+    pub fn listen(&mut self) {
+        self.inbox.clear();
+        for (radio, tuned) in self.tuned.iter().enumerate() {
+            let Some(purpose) = tuned else {
+                continue;
+            };
+            select_radio(radio);
+            if let Some(message) = receive().and_then(RadioMessage::decode) {
+                self.inbox.push((*purpose, message));
+            }
+        }
+        select_radio(0);
+    }
+
+    // Send one queued message per radio, then tune every radio for next tick
+    pub fn transmit(&mut self) {
+        for radio in 0..self.tuned.len() {
+            let waiting = self
+                .assignments
+                .iter()
+                .enumerate()
+                .filter(|(index, (_, assigned))| *assigned == radio && !self.outbox[*index].is_empty())
+                .map(|(index, (purpose, _))| (index, *purpose))
+                .next();
+            match waiting {
+                Some((index, purpose)) => {
+                    let message = self.outbox[index].pop_front().unwrap();
+                    select_radio(radio);
+                    set_radio_channel(purpose.channel());
+                    send(message.encode());
+                    self.tuned[radio] = Some(purpose);
+                    // let the other purposes on this radio go first next time
+                    let rotated = self.assignments.remove(index);
+                    let outbox = self.outbox.remove(index);
+                    self.assignments.push(rotated);
+                    self.outbox.push(outbox);
+                }
+                None => self.tune(radio),
+            }
+        }
+        select_radio(0);
+    }
+
+    // Listen for the next purpose on this radio
+    fn tune(&mut self, radio: usize) {
+        let purposes: Vec<RadioPurpose> = self
+            .assignments
+            .iter()
+            .filter(|(_, assigned)| *assigned == radio)
+            .map(|(purpose, _)| *purpose)
+            .collect();
+        if purposes.is_empty() {
+            self.tuned[radio] = None;
+            return;
+        }
+        let purpose = purposes[self.next_listen[radio] % purposes.len()];
+        self.next_listen[radio] += 1;
+        select_radio(radio);
+        set_radio_channel(purpose.channel());
+        self.tuned[radio] = Some(purpose);
+    }
+}
+
+/*****************************************************
+* FleetPicture
+* Shares the tracks our own radar holds with the fleet,
+* one a tick in id order, and merges the tracks the
+* fleet reports into our table. Relayed tracks are
+* never shared again, so a lost contact can not echo
+* around the fleet forever
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FleetPicture {
+    last_shared: TrackId,
+}
+
+impl FleetPicture {
+    pub fn create() -> FleetPicture {
+        FleetPicture { last_shared: 0 }
+    }
+
+    // The next of our tracks after the last one shared, wrapping around
+    pub fn next_report(&mut self, tracks: &TrackTable, time: f64) -> Option<RadioMessage> {
+        let shareable = || tracks.shareable(time);
+        let track = shareable()
+            .filter(|track| track.id > self.last_shared)
+            .min_by_key(|track| track.id)
+            .or_else(|| shareable().min_by_key(|track| track.id))?;
+        self.last_shared = track.id;
+        let message = RadioMessage::create(MessageID::Contact, track.id, track.position_at(time), track.velocity_at(time));
+        Some(message.with_class(track.class))
+    }
+
+    // Queue a report once the fleet channel has caught up, so reports never go stale in the queue
+    pub fn share(&mut self, tracks: &TrackTable, radio: &mut RadioManager) {
+        if radio.queued(RadioPurpose::FleetContacts) > 0 {
+            return;
+        }
+        if let Some(message) = self.next_report(tracks, current_time()) {
+            radio.queue(RadioPurpose::FleetContacts, message);
+        }
+    }
+
+    // Merge the report heard this tick, if any, and return the track it landed on
+    pub fn merge(&self, tracks: &mut TrackTable, radio: &RadioManager) -> Option<TrackId> {
+        let message = radio.receive(RadioPurpose::FleetContacts)?;
+        if message.id != MessageID::Contact || message.sender == oort_api::prelude::id() {
+            return None;
+        }
+        tracks.ingest_report(message.class, message.position, message.velocity, message.age(), current_time())
+    }
+}
@@ -0,0 +1,153 @@
+/*******************************************************************
+* ** Targeting **
+* This code is responsible for describing targets and calculating
+* the next aim (lead position) for the weapons system
+********************************************************************/
+use crate::intercept::{solve_intercept, Projectile};
+use crate::kalman::{scan_noise, ContactFilter, MeasurementNoise};
+use crate::tracking::TrackId;
+use crate::turning::mark_rate;
+use oort_api::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitDescription {
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub target_heading: f64,
+    pub distance: f64, // precalculated, index
+    pub lead_position: Option<Vec2>,
+    pub id: Option<TrackId>, // set when the description comes from a track
+}
+
+impl UnitDescription {
+    // Describe a contact as seen from our current position
+    pub fn create(class: Class, position: Vec2, velocity: Vec2, lead_position: Option<Vec2>) -> UnitDescription {
+        let position_diff = (position + velocity) - oort_api::prelude::position();
+        UnitDescription {
+            class,
+            position,
+            velocity,
+            target_heading: position_diff.angle(),
+            distance: position_diff.length(),
+            lead_position,
+            id: None,
+        }
+    }
+
+    pub fn with_id(mut self, id: TrackId) -> UnitDescription {
+        self.id = Some(id);
+        self
+    }
+}
+
+// Whether the filtered target acceleration is fed to the intercept solver
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AccelerationLead {
+    Ignore,
+    Estimated,
+}
+
+/*****************************************************
+* LeadTracker
+* Runs a Kalman filter over the measurements so the
+* smoothed velocity and acceleration can be led, then
+* solves the intercept for the configured projectile
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeadTracker {
+    pub projectile: Projectile,
+    pub acceleration_lead: AccelerationLead,
+    pub use_jitter: bool,
+    pub filter: Option<ContactFilter>,
+    pub target_velocity: Option<Vec2>,     // smoothed
+    pub target_acceleration: Option<Vec2>, // smoothed
+    pub miss_distance: Option<f64>,        // residual of the last solution
+}
+
+impl LeadTracker {
+    pub fn create(projectile: Projectile, acceleration_lead: AccelerationLead) -> LeadTracker {
+        LeadTracker {
+            projectile,
+            acceleration_lead,
+            use_jitter: false,
+            filter: None,
+            target_velocity: None,
+            target_acceleration: None,
+            miss_distance: None,
+        }
+    }
+
+    // Adds distance based jitter to widen the bullet spread at long range
+    pub fn with_jitter(mut self) -> LeadTracker {
+        self.use_jitter = true;
+        self
+    }
+
+    pub fn reset(&mut self) {
+        self.filter = None;
+        self.target_velocity = None;
+        self.target_acceleration = None;
+        self.miss_distance = None;
+    }
+
+    // Track an exact position and velocity such as target() or a radio relay,
+    // returns the lead position to aim at, None when the projectile can never catch the target
+    pub fn track(&mut self, target: Vec2, target_velocity: Vec2, velocity: Vec2) -> Option<Vec2> {
+        self.track_measurement(target, target_velocity, MeasurementNoise::exact(), velocity)
+    }
+
+    // How fast the line of sight to the lead point is turning, taking the lead point
+    // to move with the smoothed target velocity
+    pub fn mark_rate(&self, lead_position: Vec2) -> f64 {
+        mark_rate(lead_position, self.target_velocity.unwrap_or_default())
+    }
+
+    // Track a radar contact, trusting it according to its snr
+    pub fn track_contact(&mut self, contact: &ScanResult, velocity: Vec2) -> Option<Vec2> {
+        self.track_measurement(contact.position, contact.velocity, scan_noise(contact), velocity)
+    }
+
+    fn track_measurement(&mut self, target: Vec2, target_velocity: Vec2, noise: MeasurementNoise, velocity: Vec2) -> Option<Vec2> {
+        if target.x == 0.0 || target.y == 0.0 {
+            return None;
+        }
+        let now = current_time();
+        let filter = match self.filter.as_mut() {
+            Some(filter) => {
+                filter.update(target, target_velocity, noise, now);
+                *filter
+            }
+            None => *self.filter.insert(ContactFilter::create(target, target_velocity, noise, now)),
+        };
+        self.target_velocity = Some(filter.velocity());
+        self.target_acceleration = Some(filter.acceleration());
+        let acceleration = match self.acceleration_lead {
+            AccelerationLead::Ignore => vec2(0.0, 0.0),
+            AccelerationLead::Estimated => filter.acceleration(),
+        };
+        let solution = solve_intercept(position(), velocity, filter.position(), filter.velocity(), acceleration, &self.projectile);
+        self.miss_distance = solution.map(|solution| solution.miss_distance);
+        let solution = solution?;
+        let jitter = if self.use_jitter { distance_jitter(solution.time) } else { 1.0 };
+        Some(solution.aim_point + target * (jitter - 1.0))
+    }
+}
+
+// Random spread scaled by how many seconds away the target is
+pub fn distance_jitter(time_of_flight: f64) -> f64 {
+    match time_of_flight.abs() {
+        r if r >= 4.001 => rand(0.875, 1.245),
+        r if r >= 2.65 => rand(0.99854, 1.00146),
+        r if r >= 1.45 => rand(0.999985, 1.000015),
+        _ => 1.0,
+    }
+}
+
+// True once the nose is within tolerance of the target heading
+pub fn on_target(target_heading: Option<f64>, tolerance: f64) -> bool {
+    match target_heading {
+        Some(target_heading) => angle_diff(heading(), target_heading).abs() < tolerance,
+        None => false,
+    }
+}
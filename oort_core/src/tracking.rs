@@ -0,0 +1,309 @@
+/*******************************************************************
+* ** Tracking **
+* Keeps one Kalman filtered track per contact the radar has seen.
+* Each scan is associated with the nearest track whose predicted
+* position falls inside its gate, or starts a new tentative track.
+* Tracks confirm after repeated hits, coast when the radar stops
+* seeing them and are dropped once they have coasted too long.
+* Tracks the rest of the fleet reports over the radio merge in
+* the same way, with noise that grows with the report's age
+********************************************************************/
+use crate::kalman::{scan_noise, ContactFilter, MeasurementNoise};
+use crate::targeting::UnitDescription;
+use oort_api::prelude::*;
+
+pub type TrackId = u32;
+
+pub const DEFAULT_GATE: f64 = 4.0; // sigmas of predicted position error
+pub const MINIMUM_GATE: f64 = 50.0; // meters
+pub const CONFIRM_HITS: u32 = 3;
+pub const COAST_AFTER: f64 = 1.0; // seconds without an update
+pub const DROP_AFTER: f64 = 5.0; // seconds without an update
+pub const REPORT_POSITION_SIGMA: f64 = 10.0; // meters, a sender's filtered track
+pub const REPORT_VELOCITY_SIGMA: f64 = 5.0; // m/s
+pub const REPORT_DRIFT_SIGMA: f64 = 30.0; // meters per second of report age, unknown acceleration
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackState {
+    Tentative, // seen, not yet trusted
+    Confirmed,
+    Coasting, // confirmed but not seen lately, position is predicted
+    Dropped,  // removed on the next update
+}
+
+/*****************************************************
+* Track
+* One contact over time
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Track {
+    pub id: TrackId,
+    pub class: Class,
+    pub state: TrackState,
+    pub filter: ContactFilter,
+    pub hits: u32,
+    pub first_seen: f64,
+    pub last_seen: f64,
+    pub last_local: Option<f64>, // when our own radar last saw it, None if only reported
+}
+
+impl Track {
+    pub fn age(&self, time: f64) -> f64 {
+        time - self.first_seen
+    }
+
+    pub fn since_seen(&self, time: f64) -> f64 {
+        time - self.last_seen
+    }
+
+    // Confirmed or coasting, worth shooting at
+    pub fn is_firm(&self) -> bool {
+        matches!(self.state, TrackState::Confirmed | TrackState::Coasting)
+    }
+
+    // Held by our own radar rather than relayed by the fleet
+    pub fn is_local(&self, time: f64, within: f64) -> bool {
+        self.last_local.is_some_and(|last_local| time - last_local <= within)
+    }
+
+    pub fn position_at(&self, time: f64) -> Vec2 {
+        self.filter.extrapolate(time - self.filter.time)
+    }
+
+    pub fn velocity_at(&self, time: f64) -> Vec2 {
+        self.filter.velocity() + self.filter.acceleration() * (time - self.filter.time)
+    }
+
+    // The track predicted to `time`, as seen from our current position
+    pub fn describe(&self, time: f64) -> UnitDescription {
+        UnitDescription::create(self.class, self.position_at(time), self.velocity_at(time), None).with_id(self.id)
+    }
+}
+
+/*****************************************************
+* TrackTable
+* Every live track, with the association gate and
+* lifecycle timings
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackTable {
+    pub tracks: Vec<Track>,
+    pub gate: f64,
+    pub minimum_gate: f64,
+    pub confirm_hits: u32,
+    pub coast_after: f64,
+    pub drop_after: f64,
+    next_id: TrackId,
+}
+
+impl TrackTable {
+    pub fn create() -> TrackTable {
+        TrackTable {
+            tracks: Vec::new(),
+            gate: DEFAULT_GATE,
+            minimum_gate: MINIMUM_GATE,
+            confirm_hits: CONFIRM_HITS,
+            coast_after: COAST_AFTER,
+            drop_after: DROP_AFTER,
+            next_id: 1,
+        }
+    }
+
+    pub fn with_gate(mut self, sigmas: f64, minimum: f64) -> TrackTable {
+        self.gate = sigmas;
+        self.minimum_gate = minimum;
+        self
+    }
+
+    pub fn with_confirmation(mut self, hits: u32) -> TrackTable {
+        self.confirm_hits = hits;
+        self
+    }
+
+    pub fn with_timeouts(mut self, coast_after: f64, drop_after: f64) -> TrackTable {
+        self.coast_after = coast_after;
+        self.drop_after = drop_after;
+        self
+    }
+
+    // Associate a scan taken this tick
+    pub fn ingest(&mut self, contact: &ScanResult) -> TrackId {
+        self.ingest_measurement(contact.class, contact.position, contact.velocity, scan_noise(contact), current_time())
+    }
+
+    // Associate a measurement with the nearest gated track, or start a new one
+    pub fn ingest_measurement(&mut self, class: Class, position: Vec2, velocity: Vec2, noise: MeasurementNoise, time: f64) -> TrackId {
+        let confirm_hits = self.confirm_hits;
+        if let Some(index) = self.associate(class, position, noise, time) {
+            let track = &mut self.tracks[index];
+            track.filter.update(position, velocity, noise, time);
+            track.hits += 1;
+            track.last_seen = time;
+            track.last_local = Some(time);
+            if track.class == Class::Unknown {
+                track.class = class;
+            }
+            if track.hits >= confirm_hits {
+                track.state = TrackState::Confirmed;
+            }
+            return track.id;
+        }
+        let state = if confirm_hits <= 1 { TrackState::Confirmed } else { TrackState::Tentative };
+        let filter = ContactFilter::create(position, velocity, noise, time);
+        self.start_track(class, filter, state, time, Some(time))
+    }
+
+    // Merge a track another ship reported `age` seconds ago. The sender only shares
+    // confirmed tracks, so a report confirms. Reports older than the drop timeout are ignored
+    pub fn ingest_report(&mut self, class: Class, position: Vec2, velocity: Vec2, age: f64, time: f64) -> Option<TrackId> {
+        if age > self.drop_after {
+            return None;
+        }
+        let age = age.max(0.0);
+        let position = position + velocity * age;
+        let noise = MeasurementNoise::create(REPORT_POSITION_SIGMA + REPORT_DRIFT_SIGMA * age, REPORT_VELOCITY_SIGMA);
+        let seen = time - age;
+        if let Some(index) = self.associate(class, position, noise, time) {
+            let track = &mut self.tracks[index];
+            track.filter.update(position, velocity, noise, time);
+            track.hits += 1;
+            track.last_seen = track.last_seen.max(seen);
+            if track.class == Class::Unknown {
+                track.class = class;
+            }
+            if track.last_seen >= time - self.coast_after {
+                track.state = TrackState::Confirmed;
+            }
+            return Some(track.id);
+        }
+        let filter = ContactFilter::create(position, velocity, noise, time);
+        Some(self.start_track(class, filter, TrackState::Confirmed, seen, None))
+    }
+
+    // Index of the nearest live track whose gate holds `position`
+    fn associate(&self, class: Class, position: Vec2, noise: MeasurementNoise, time: f64) -> Option<usize> {
+        self.tracks
+            .iter()
+            .enumerate()
+            .filter(|(_, track)| track.state != TrackState::Dropped && classes_match(track.class, class))
+            .map(|(index, track)| {
+                let mut predicted = track.filter;
+                predicted.predict(time);
+                let variance = predicted.position_variance();
+                let radius = (self.gate * (variance.x + variance.y + noise.position * noise.position).sqrt()).max(self.minimum_gate);
+                let distance = (predicted.position() - position).length();
+                (index, distance, radius)
+            })
+            .filter(|(_, distance, radius)| distance <= radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(index, _, _)| index)
+    }
+
+    fn start_track(&mut self, class: Class, filter: ContactFilter, state: TrackState, seen: f64, last_local: Option<f64>) -> TrackId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tracks.push(Track {
+            id,
+            class,
+            state,
+            filter,
+            hits: 1,
+            first_seen: seen,
+            last_seen: seen,
+            last_local,
+        });
+        id
+    }
+
+    // Advance the lifecycle, call once per tick after ingesting
+    pub fn update(&mut self, time: f64) {
+        self.tracks.retain(|track| track.state != TrackState::Dropped);
+        self.fold_duplicates(time);
+        for track in self.tracks.iter_mut() {
+            let since_seen = track.since_seen(time);
+            track.state = match track.state {
+                TrackState::Tentative if since_seen > self.coast_after => TrackState::Dropped,
+                TrackState::Confirmed if since_seen > self.coast_after => TrackState::Coasting,
+                TrackState::Coasting if since_seen > self.drop_after => TrackState::Dropped,
+                state => state,
+            };
+        }
+    }
+
+    // A reported track that turns out to be one we already hold is folded into ours
+    // once their gates overlap. Two tracks our own radar holds are never folded, it
+    // can tell them apart
+    fn fold_duplicates(&mut self, time: f64) {
+        for keep in 0..self.tracks.len() {
+            for other in keep + 1..self.tracks.len() {
+                let (a, b) = (&self.tracks[keep], &self.tracks[other]);
+                if a.state == TrackState::Dropped
+                    || b.state == TrackState::Dropped
+                    || !classes_match(a.class, b.class)
+                    || (a.last_local.is_some() && b.last_local.is_some())
+                    || !self.within_gate(a, b, time)
+                {
+                    continue;
+                }
+                // keep the one our radar holds, or the older one
+                let (kept, folded) = if b.last_local.is_some() && a.last_local.is_none() {
+                    (other, keep)
+                } else {
+                    (keep, other)
+                };
+                let folded_track = self.tracks[folded];
+                self.tracks[folded].state = TrackState::Dropped;
+                let track = &mut self.tracks[kept];
+                track.hits += folded_track.hits;
+                track.first_seen = track.first_seen.min(folded_track.first_seen);
+                track.last_seen = track.last_seen.max(folded_track.last_seen);
+                if track.class == Class::Unknown {
+                    track.class = folded_track.class;
+                }
+                if folded_track.is_firm() && track.state == TrackState::Tentative {
+                    track.state = TrackState::Confirmed;
+                }
+            }
+        }
+    }
+
+    // Whether two tracks could be the same contact given both their uncertainties
+    fn within_gate(&self, a: &Track, b: &Track, time: f64) -> bool {
+        let (mut a, mut b) = (a.filter, b.filter);
+        a.predict(time);
+        b.predict(time);
+        let variance = a.position_variance() + b.position_variance();
+        let radius = (self.gate * (variance.x + variance.y).sqrt()).max(self.minimum_gate);
+        (a.position() - b.position()).length() <= radius
+    }
+
+    pub fn get(&self, id: TrackId) -> Option<&Track> {
+        self.tracks.iter().find(|track| track.id == id && track.state != TrackState::Dropped)
+    }
+
+    pub fn firm(&self) -> impl Iterator<Item = &Track> {
+        self.tracks.iter().filter(|track| track.is_firm())
+    }
+
+    // Confirmed tracks our own radar is holding, the ones worth sharing with the fleet
+    pub fn shareable(&self, time: f64) -> impl Iterator<Item = &Track> {
+        let coast_after = self.coast_after;
+        self.tracks
+            .iter()
+            .filter(move |track| track.state == TrackState::Confirmed && track.is_local(time, coast_after))
+    }
+
+    // Closest confirmed or coasting track to `position`, predicted to `time`
+    pub fn nearest(&self, position: Vec2, time: f64) -> Option<&Track> {
+        self.firm()
+            .min_by(|a, b| (a.position_at(time) - position).length().total_cmp(&(b.position_at(time) - position).length()))
+    }
+
+    pub fn clear(&mut self) {
+        self.tracks.clear();
+    }
+}
+
+fn classes_match(a: Class, b: Class) -> bool {
+    a == b || a == Class::Unknown || b == Class::Unknown
+}
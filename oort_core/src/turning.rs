@@ -0,0 +1,241 @@
+/********************************************************************************************************************
+* ** Turning System **
+* Calculates the next torque value needed to bring the ship's heading onto a mark.
+* Angles passed in are "angle to mark", i.e. angle_diff(heading(), target_heading)
+*******************************************************************************************************************/
+use crate::constants::TICKS_PER_SECOND;
+use crate::guidance::LineOfSight;
+use oort_api::prelude::*;
+
+/*****************************************************
+* HeadingController
+* One way of turning the nose onto a mark. Each is
+* given the rate the mark turns at so it can hold a
+* moving lead point, 0.0 for a fixed mark. Ships pick
+* theirs at construction, heading_controller(class())
+* being the default for the class
+*******************************************************/
+pub trait HeadingController {
+    // Torque for this tick from the angle to the mark, the spin and the torque limit
+    fn torque_for(&self, angle_to_mark: f64, spin: f64, mark_rate: f64, max_acceleration: f64) -> f64;
+
+    // torque_for this ship onto a fixed mark
+    fn torque(&self, angle_to_mark: f64) -> f64 {
+        self.track(angle_to_mark, 0.0)
+    }
+
+    // torque_for this ship onto a mark turning at mark_rate
+    fn track(&self, angle_to_mark: f64, mark_rate: f64) -> f64 {
+        self.torque_for(angle_to_mark, angular_velocity(), mark_rate, max_angular_acceleration())
+    }
+}
+
+// Fighters keep the tuned hybrid. The heavier hulls turn too slowly for PD gains tuned
+// on a fighter and overshoot, the bang-bang turn knows their limit
+pub fn heading_controller(class: Class) -> Box<dyn HeadingController> {
+    match class {
+        Class::Fighter => Box::new(FIGHTER_TURN),
+        _ => Box::new(BangBangTurn),
+    }
+}
+
+/*****************************************************
+* PdTurn
+* Torque proportional to the error, damped by the
+* spin relative to the mark
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdTurn {
+    pub proportional: f64,
+    pub derivative: f64,
+}
+
+impl PdTurn {
+    pub const fn create(proportional: f64, derivative: f64) -> PdTurn {
+        PdTurn { proportional, derivative }
+    }
+}
+
+impl HeadingController for PdTurn {
+    fn torque_for(&self, angle_to_mark: f64, spin: f64, mark_rate: f64, _max_acceleration: f64) -> f64 {
+        self.proportional * angle_to_mark - self.derivative * (spin - mark_rate)
+    }
+}
+
+/*****************************************************
+* CriticallyDampedTurn
+* A PD turn with the damping 2 * sqrt(k) that settles
+* without ringing, optionally stiffened inside
+* stiff_angle (captain-seahorse turning)
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CriticallyDampedTurn {
+    pub tune_factor: f64,
+    pub stiff_angle: f64,
+    pub stiff_tune_factor: f64,
+}
+
+// captain-seahorse turning solution, soft far from the mark and stiff close to it
+pub const SEAHORSE_TURN: CriticallyDampedTurn = CriticallyDampedTurn::create(40.0).with_stiff_tune_factor(0.1, 10_000.0);
+
+impl CriticallyDampedTurn {
+    pub const fn create(tune_factor: f64) -> CriticallyDampedTurn {
+        CriticallyDampedTurn {
+            tune_factor,
+            stiff_angle: 0.0,
+            stiff_tune_factor: tune_factor,
+        }
+    }
+
+    pub const fn with_stiff_tune_factor(mut self, stiff_angle: f64, stiff_tune_factor: f64) -> CriticallyDampedTurn {
+        self.stiff_angle = stiff_angle;
+        self.stiff_tune_factor = stiff_tune_factor;
+        self
+    }
+}
+
+impl HeadingController for CriticallyDampedTurn {
+    fn torque_for(&self, angle_to_mark: f64, spin: f64, mark_rate: f64, max_acceleration: f64) -> f64 {
+        let tune_factor = if angle_to_mark.abs() > self.stiff_angle {
+            self.tune_factor
+        } else {
+            self.stiff_tune_factor
+        };
+        PdTurn::create(tune_factor, 2.0 * tune_factor.sqrt()).torque_for(angle_to_mark, spin, mark_rate, max_acceleration)
+    }
+}
+
+/*****************************************************
+* Bang-Bang Turn
+* Minimum time rotation under the torque limit a: full
+* torque toward the mark, then full torque against
+* the spin, switching where the braking curve
+*   w = sqrt(2 a |e|)
+* crosses the current spin. The curve used is the
+* discrete one: the spin from which braking tick by
+* tick stops exactly on the mark, down to the last
+* tick, which asks for exactly the spin that covers
+* what is left. A mark behind us is
+* reached whichever way round is quicker, so a fast
+* spin the wrong way carries on round instead of
+* reversing when that wins
+*******************************************************/
+const TICK: f64 = 1.0 / TICKS_PER_SECOND;
+
+// Seconds a minimum time turn takes to come to rest on the mark
+pub fn min_turn_time(angle_to_mark: f64, spin: f64, max_acceleration: f64) -> f64 {
+    if angle_to_mark < 0.0 {
+        return min_turn_time(-angle_to_mark, -spin, max_acceleration);
+    }
+    if spin > 0.0 && spin * spin / (2.0 * max_acceleration) > angle_to_mark {
+        // overshoots, brake past the mark and come back
+        (spin + 2.0 * (spin * spin / 2.0 - max_acceleration * angle_to_mark).sqrt()) / max_acceleration
+    } else {
+        (-spin + 2.0 * (spin * spin / 2.0 + max_acceleration * angle_to_mark).sqrt()) / max_acceleration
+    }
+}
+
+// The way round to the mark that takes least time, as a signed angle
+pub fn quickest_way_round(angle_to_mark: f64, spin: f64, max_acceleration: f64) -> f64 {
+    let other_way = angle_to_mark - TAU.copysign(angle_to_mark);
+    if min_turn_time(other_way, spin, max_acceleration) < min_turn_time(angle_to_mark, spin, max_acceleration) {
+        other_way
+    } else {
+        angle_to_mark
+    }
+}
+
+// Torque for this tick of a minimum time turn
+pub fn bang_bang_torque(angle_to_mark: f64, spin: f64, max_acceleration: f64) -> f64 {
+    let error = quickest_way_round(angle_to_mark, spin, max_acceleration);
+    ((braking_speed(error.abs(), max_acceleration).copysign(error) - spin) / TICK).clamp(-max_acceleration, max_acceleration)
+}
+
+// Fastest spin (or speed, the integrator is the same) that still stops on a mark `distance`
+// away. Braking from w loses one step s = a * TICK a tick, so moving this tick and then
+// braking for n ticks covers
+//   TICK * ((n + 1) * w - s * n * (n + 1) / 2),  n = floor(w / s)
+// which is solved for w, starting from the continuous curve's n
+pub(crate) fn braking_speed(distance: f64, max_acceleration: f64) -> f64 {
+    let step = max_acceleration * TICK;
+    let speed_for = |n: f64| (distance / TICK + step * n * (n + 1.0) / 2.0) / (n + 1.0);
+    let mut n = (((2.0 * max_acceleration * distance + step * step / 4.0).sqrt() - step / 2.0) / step)
+        .floor()
+        .max(0.0);
+    for _ in 0..4 {
+        let speed = speed_for(n);
+        if speed < n * step && n > 0.0 {
+            n -= 1.0;
+        } else if speed >= (n + 1.0) * step {
+            n += 1.0;
+        } else {
+            break;
+        }
+    }
+    speed_for(n)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BangBangTurn;
+
+impl HeadingController for BangBangTurn {
+    fn torque_for(&self, angle_to_mark: f64, spin: f64, mark_rate: f64, max_acceleration: f64) -> f64 {
+        tracking_torque(angle_to_mark, spin, mark_rate, max_acceleration)
+    }
+}
+
+/*****************************************************
+* Tracking Turn
+* A lead point that moves sweeps the mark round at
+* the line of sight rate. Seen from a frame turning
+* with the mark it is a fixed mark again, so the
+* bang-bang turn runs on the spin relative to the
+* mark and the mark's rate is fed forward on top.
+* On the mark the ship turns with it instead of
+* braking to a stop and falling behind
+*******************************************************/
+
+// Rate the line of sight to an aim point moving at aim_velocity turns at, rad/s
+pub fn mark_rate(aim_point: Vec2, aim_velocity: Vec2) -> f64 {
+    LineOfSight::create(aim_point - position(), aim_velocity - velocity()).map_or(0.0, |line_of_sight| line_of_sight.rate)
+}
+
+// Torque for this tick of a minimum time turn onto a mark turning at mark_rate
+pub fn tracking_torque(angle_to_mark: f64, spin: f64, mark_rate: f64, max_acceleration: f64) -> f64 {
+    bang_bang_torque(angle_to_mark, spin - mark_rate, max_acceleration)
+}
+
+/*****************************************************
+* HybridTurn
+* Uses a PD turn scaled by the error while far from
+* the mark, then hands over to the bang-bang turn
+* once inside switch_angle
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HybridTurn {
+    pub switch_angle: f64,
+    pub tune_factor: f64,
+}
+
+// Tuning used by the Deflection and Radar solutions
+pub const DEFLECTION_TURN: HybridTurn = HybridTurn::create(0.25, 55.79);
+// Tuning used by the Search, Radio, Missiles and Squadron solutions
+pub const FIGHTER_TURN: HybridTurn = HybridTurn::create(0.205, 55.69);
+// Tighter hand over used by the Castle Missiles fighter
+pub const CASTLE_TURN: HybridTurn = HybridTurn::create(0.115, 55.69);
+
+impl HybridTurn {
+    pub const fn create(switch_angle: f64, tune_factor: f64) -> HybridTurn {
+        HybridTurn { switch_angle, tune_factor }
+    }
+}
+
+impl HeadingController for HybridTurn {
+    fn torque_for(&self, angle_to_mark: f64, spin: f64, mark_rate: f64, max_acceleration: f64) -> f64 {
+        if angle_to_mark.abs() > self.switch_angle {
+            CriticallyDampedTurn::create(self.tune_factor * angle_to_mark.abs()).torque_for(angle_to_mark, spin, mark_rate, max_acceleration)
+        } else {
+            tracking_torque(angle_to_mark, spin, mark_rate, max_acceleration)
+        }
+    }
+}
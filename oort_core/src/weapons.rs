@@ -0,0 +1,181 @@
+/*******************************************************************
+* ** Weapon Systems **
+* This code is responsible for aiming turrets, and firing weapons
+* using configured parameters
+********************************************************************/
+use crate::constants::{TICKS_PER_FIRE, TICKS_PER_SECOND};
+use crate::radar::class_threat;
+use crate::targeting::UnitDescription;
+use crate::tracking::TrackId;
+use oort_api::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BurstGun {
+    pub index: usize,
+    pub use_burst_fire: bool,
+    pub should_fire: bool,
+    pub burst_fire: u32,  // shots per burst
+    pub burst_pause: u32, // pause between bursts, in shots
+    fire_count: u32,
+}
+
+impl BurstGun {
+    pub fn create(index: usize, burst_fire: u32, burst_pause: u32) -> BurstGun {
+        BurstGun {
+            index,
+            use_burst_fire: false,
+            should_fire: false,
+            burst_fire,
+            burst_pause,
+            fire_count: 0,
+        }
+    }
+
+    pub fn with_burst_fire(mut self) -> BurstGun {
+        self.use_burst_fire = true;
+        self
+    }
+
+    pub fn start_firing(&mut self, burst_count: u32) {
+        self.should_fire = true;
+        self.burst_fire = burst_count;
+    }
+
+    pub fn fire_burst(&mut self) {
+        if self.fire_count / TICKS_PER_FIRE >= (self.burst_fire + self.burst_pause) {
+            self.fire_count = 0;
+            self.should_fire = false;
+        } else {
+            if self.fire_count / TICKS_PER_FIRE < self.burst_fire {
+                fire(self.index);
+            }
+            self.fire_count += 1;
+        }
+    }
+
+    pub fn update(&mut self) {
+        if self.should_fire {
+            if self.use_burst_fire {
+                self.fire_burst();
+            } else {
+                fire(self.index);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mount {
+    Turret,
+    Launcher(Class), // what it launches
+}
+
+/*****************************************************
+* WeaponSlot
+* One weapon mount and its own reload timer, counted
+* down the same way the game does
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeaponSlot {
+    pub index: usize,
+    pub mount: Mount,
+    pub reload_time: u32, // ticks
+    pub ticks_till_reload: u32,
+}
+
+impl WeaponSlot {
+    pub fn create(index: usize, mount: Mount, reload_time: u32) -> WeaponSlot {
+        WeaponSlot {
+            index,
+            mount,
+            reload_time,
+            ticks_till_reload: 0,
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ticks_till_reload == 0
+    }
+
+    // Call once at the top of each tick
+    pub fn update(&mut self) {
+        self.ticks_till_reload = self.ticks_till_reload.saturating_sub(1);
+    }
+
+    // Fire if loaded, returning whether it went
+    pub fn fire(&mut self) -> bool {
+        if !self.is_ready() {
+            return false;
+        }
+        fire(self.index);
+        self.ticks_till_reload = self.reload_time;
+        true
+    }
+}
+
+// How urgently a target needs a gun on it: threatening classes first, then the
+// closest and fastest closing
+pub fn engagement_priority(target: &UnitDescription) -> f64 {
+    let offset = target.position - position();
+    let range = offset.length().max(1.0);
+    let closing = -offset.dot(target.velocity - velocity()) / range;
+    class_threat(target.class) * (1.0 + closing.max(0.0) / 500.0) / range
+}
+
+// Pair each turret with a target, most urgent first. Turrets left over double up on
+// the targets in the same order
+pub fn allocate_turrets(turrets: &[usize], targets: &[UnitDescription]) -> Vec<(usize, UnitDescription)> {
+    let mut ranked = targets.to_vec();
+    ranked.sort_by(|a, b| engagement_priority(b).total_cmp(&engagement_priority(a)));
+    if ranked.is_empty() {
+        return Vec::new();
+    }
+    turrets
+        .iter()
+        .enumerate()
+        .map(|(slot, turret)| (*turret, ranked[slot % ranked.len()]))
+        .collect()
+}
+
+/*****************************************************
+* Salvo
+* Launchers fired at one track so everything lands
+* together: the slowest weapon goes first and each of
+* the others waits until its own flight time brings
+* it in at the same moment
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Salvo {
+    pub track: TrackId,
+    pub arrival: f64,        // current_time() the salvo lands
+    pub pending: Vec<usize>, // launchers yet to fire
+}
+
+impl Salvo {
+    // Launchers as (weapon index, flight time), None if there are none
+    pub fn create(track: TrackId, launchers: &[(usize, f64)], time: f64) -> Option<Salvo> {
+        let slowest = launchers.iter().map(|(_, flight_time)| *flight_time).reduce(f64::max)?;
+        Some(Salvo {
+            track,
+            arrival: time + slowest,
+            pending: launchers.iter().map(|(index, _)| *index).collect(),
+        })
+    }
+
+    pub fn is_pending(&self, index: usize) -> bool {
+        self.pending.contains(&index)
+    }
+
+    // Whether a launcher fired now would land with the salvo, or is already late
+    pub fn is_due(&self, index: usize, flight_time: f64, time: f64) -> bool {
+        self.pending.contains(&index) && time + flight_time >= self.arrival - 0.5 / TICKS_PER_SECOND
+    }
+
+    pub fn launched(&mut self, index: usize) {
+        self.pending.retain(|pending| *pending != index);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
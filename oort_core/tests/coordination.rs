@@ -0,0 +1,140 @@
+/**************************************************************
+* Coordination tests
+* HiveMind leadership and target assignment, and the radio
+* messages the squadron coordinates with
+****************************************************************/
+use oort_api::mock::{self, Environment};
+use oort_api::prelude::*;
+use oort_core::coordination::{is_report_tick, position_report, read_assignment, Friendly, HiveMind, REPORT_SLOTS};
+use oort_core::kalman::MeasurementNoise;
+use oort_core::radio::{MessageID, RadioMessage};
+use oort_core::tracking::{TrackId, TrackTable};
+
+fn fighter(id: u32, position: Vec2, heading: f64) -> Friendly {
+    Friendly {
+        id,
+        class: Class::Fighter,
+        position,
+        velocity: vec2(0.0, 0.0),
+        heading,
+        last_heard: 0.0,
+    }
+}
+
+fn enemy(tracks: &mut TrackTable, position: Vec2, time: f64) -> TrackId {
+    tracks.ingest_measurement(Class::Fighter, position, vec2(0.0, 0.0), MeasurementNoise::exact(), time)
+}
+
+fn assigned(hive: &HiveMind, ship: u32) -> Option<TrackId> {
+    hive.assignment(ship).map(|assignment| assignment.track)
+}
+
+#[test]
+fn fighters_take_the_target_on_their_nose() {
+    let mut tracks = TrackTable::create().with_confirmation(1);
+    let east = enemy(&mut tracks, vec2(3000.0, 0.0), 0.0);
+    let west = enemy(&mut tracks, vec2(-3000.0, 0.0), 0.0);
+    let mut hive = HiveMind::create();
+    hive.register_ship(fighter(1, vec2(0.0, 0.0), 0.0));
+    hive.register_ship(fighter(2, vec2(0.0, 100.0), PI));
+    hive.assign(&tracks, 0.0);
+    assert_eq!(assigned(&hive, 1), Some(east));
+    assert_eq!(assigned(&hive, 2), Some(west));
+}
+
+#[test]
+fn load_spreads_fighters_over_targets() {
+    let mut tracks = TrackTable::create().with_confirmation(1);
+    let near = enemy(&mut tracks, vec2(2000.0, 0.0), 0.0);
+    let far = enemy(&mut tracks, vec2(3000.0, 500.0), 0.0);
+    let mut hive = HiveMind::create();
+    for ship in 1..=3 {
+        hive.register_ship(fighter(ship, vec2(0.0, ship as f64 * 10.0), 0.0));
+    }
+    let targets: Vec<TrackId> = hive.assign(&tracks, 0.0).iter().map(|assignment| assignment.track).collect();
+    assert_eq!(targets.len(), 3);
+    assert_eq!(targets.iter().filter(|track| **track == near).count(), 2);
+    assert_eq!(targets.iter().filter(|track| **track == far).count(), 1);
+
+    let mut crowded = hive.clone().with_weights(1.0, 1.0, 0.0);
+    assert!(crowded.assign(&tracks, 0.0).iter().all(|assignment| assignment.track == near));
+}
+
+#[test]
+fn dead_target_is_reassigned() {
+    let mut tracks = TrackTable::create().with_confirmation(1);
+    let first = enemy(&mut tracks, vec2(2000.0, 0.0), 0.0);
+    let mut hive = HiveMind::create();
+    hive.register_ship(fighter(1, vec2(0.0, 0.0), 0.0));
+    hive.assign(&tracks, 0.0);
+    assert_eq!(assigned(&hive, 1), Some(first));
+
+    // the first target stops answering and coasts, a new one shows up
+    let second = enemy(&mut tracks, vec2(-4000.0, 0.0), 1.5);
+    tracks.update(1.5);
+    hive.assign(&tracks, 1.5);
+    assert_eq!(assigned(&hive, 1), Some(second));
+}
+
+#[test]
+fn current_target_is_sticky() {
+    let mut tracks = TrackTable::create().with_confirmation(1);
+    let held = enemy(&mut tracks, vec2(2100.0, 0.0), 0.0);
+    let mut hive = HiveMind::create();
+    hive.register_ship(fighter(1, vec2(0.0, 0.0), 0.0));
+    hive.assign(&tracks, 0.0);
+    enemy(&mut tracks, vec2(2000.0, 100.0), 0.0);
+    hive.assign(&tracks, 0.0);
+    assert_eq!(assigned(&hive, 1), Some(held), "a slightly closer target is not worth switching for");
+}
+
+#[test]
+fn lowest_id_heard_leads_until_it_goes_quiet() {
+    let mut hive = HiveMind::create();
+    hive.register_ship(fighter(4, vec2(0.0, 0.0), 0.0));
+    assert_eq!(hive.leader(7), 4);
+    assert_eq!(hive.leader(2), 2);
+    hive.forget(2.5);
+    assert!(hive.friendlies.is_empty());
+    assert_eq!(hive.leader(7), 7);
+}
+
+#[test]
+fn position_report_carries_heading() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.id = 3;
+    env.position = vec2(100.0, -200.0);
+    env.velocity = vec2(10.0, 0.0);
+    env.heading = -PI / 2.0;
+    mock::install(env);
+    let report = RadioMessage::decode(position_report().encode()).unwrap();
+    let friendly = Friendly::from_report(&report, 0.0).unwrap();
+    assert_eq!((friendly.id, friendly.class, friendly.position), (3, Class::Fighter, vec2(100.0, -200.0)));
+    assert!(angle_diff(friendly.heading, -PI / 2.0).abs() < 1e-3);
+}
+
+#[test]
+fn assignments_go_out_in_turn_to_their_ship() {
+    mock::install(Environment::for_class(Class::Fighter));
+    let mut tracks = TrackTable::create().with_confirmation(1);
+    enemy(&mut tracks, vec2(2000.0, 0.0), 0.0);
+    let mut hive = HiveMind::create();
+    hive.register_ship(fighter(1, vec2(0.0, 0.0), 0.0));
+    hive.register_ship(fighter(2, vec2(0.0, 100.0), 0.0));
+    hive.assign(&tracks, 0.0);
+    let ships: Vec<u32> = (0..3).map(|_| hive.next_message(&tracks, 0.0).unwrap().track).collect();
+    assert_eq!(ships, [1, 2, 1]);
+
+    let message = RadioMessage::decode(hive.next_message(&tracks, 0.0).unwrap().encode()).unwrap();
+    assert_eq!(message.id, MessageID::Assignment);
+    assert_eq!(read_assignment(&message, 1), None, "addressed to ship 2");
+    let target = read_assignment(&message, 2).unwrap();
+    assert_eq!((target.class, target.position), (Class::Fighter, vec2(2000.0, 0.0)));
+}
+
+#[test]
+fn each_ship_talks_once_a_round() {
+    for id in 0..REPORT_SLOTS * 2 {
+        assert_eq!((0..REPORT_SLOTS).filter(|tick| is_report_tick(id, *tick)).count(), 1);
+    }
+}
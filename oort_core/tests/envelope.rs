@@ -0,0 +1,116 @@
+/**************************************************************
+* Missile envelope tests
+* Intercepts worked by hand, reach and no-escape zones, and a
+* missile flown through boost, coast and terminal on its plan
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::envelope::{MissileEnergy, MissilePhase, COAST_MISS, TERMINAL_TIME};
+
+const DT: f64 = 1.0 / 60.0;
+
+fn energy(fuel: f64) -> MissileEnergy {
+    MissileEnergy::create(fuel, 300.0)
+}
+
+#[test]
+fn stationary_target_is_met_mid_burn() {
+    // 5km at 300 m/s^2 from rest takes sqrt(2 * 5000 / 300) seconds of burning
+    let plan = energy(2000.0)
+        .plan(vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(5000.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0))
+        .unwrap();
+    assert!((plan.time - (2.0 * 5000.0 / 300.0_f64).sqrt()).abs() <= DT, "{plan:?}");
+    assert!(plan.delta_v <= 300.0 * plan.time);
+    assert_eq!(plan.direction, vec2(1.0, 0.0));
+}
+
+#[test]
+fn fuel_limits_the_reach() {
+    let missile = energy(2000.0);
+    let target = vec2(8000.0, 0.0);
+    assert!(missile.can_reach(vec2(0.0, 0.0), vec2(0.0, 0.0), target, vec2(0.0, 0.0)));
+    assert!(
+        !missile.can_reach(vec2(0.0, 0.0), vec2(0.0, 0.0), target, vec2(2500.0, 0.0)),
+        "outrunning the tank"
+    );
+    assert!(
+        !energy(100.0).can_reach(vec2(0.0, 0.0), vec2(0.0, 0.0), target, vec2(0.0, 0.0)),
+        "too little fuel to cover 8km in time"
+    );
+}
+
+#[test]
+fn no_escape_zone_depends_on_how_hard_the_target_can_pull() {
+    let missile = MissileEnergy::missile();
+    let (origin, still) = (vec2(0.0, 0.0), vec2(0.0, 0.0));
+    assert!(missile.no_escape(origin, still, vec2(3000.0, 0.0), still, Class::Fighter));
+    // a fighter this far out is hit only if it holds course
+    let far = vec2(25000.0, 0.0);
+    assert!(missile.can_reach(origin, still, far, still));
+    assert!(!missile.no_escape(origin, still, far, still, Class::Fighter));
+    assert!(missile.no_escape(origin, still, far, still, Class::Cruiser));
+}
+
+#[test]
+fn phases_follow_the_geometry() {
+    let missile = energy(2000.0);
+    let (phase, plan) = missile.phase(vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(5000.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+    assert_eq!(phase, MissilePhase::Boost);
+    assert_eq!(plan.unwrap().direction, vec2(1.0, 0.0));
+    // already on a collision course
+    let (phase, _) = missile.phase(vec2(0.0, 0.0), vec2(1000.0, 0.0), vec2(5000.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+    assert_eq!(phase, MissilePhase::Coast);
+    let (phase, plan) = missile.phase(vec2(0.0, 0.0), vec2(1000.0, 0.0), vec2(1000.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+    assert_eq!((phase, plan), (MissilePhase::Terminal, None));
+}
+
+#[test]
+fn boost_keeps_the_terminal_reserve_and_coasts_onto_the_target() {
+    let mut missile = (vec2(0.0, 0.0), vec2(0.0, 0.0));
+    let mut target = (vec2(15000.0, 3000.0), vec2(0.0, -150.0));
+    let mut fuel = 2000.0;
+    let mut phases = Vec::new();
+    loop {
+        let energy = energy(fuel);
+        let (phase, plan) = energy.phase(missile.0, missile.1, target.0, target.1, vec2(0.0, 0.0));
+        if phases.last() != Some(&phase) {
+            phases.push(phase);
+        }
+        let acceleration = match (phase, plan) {
+            (MissilePhase::Boost, Some(plan)) => plan.burn(energy.max_acceleration),
+            (MissilePhase::Coast, _) => vec2(0.0, 0.0),
+            _ => break,
+        };
+        fuel -= acceleration.length() * DT;
+        missile = (missile.0 + missile.1 * DT + acceleration * (0.5 * DT * DT), missile.1 + acceleration * DT);
+        target.0 += target.1 * DT;
+    }
+    assert_eq!(phases, vec![MissilePhase::Boost, MissilePhase::Coast, MissilePhase::Terminal]);
+    assert!(fuel >= energy(0.0).terminal_reserve - 1.0, "{fuel} left");
+    // coasting on from the hand over still passes close by
+    let (offset, closing) = (target.0 - missile.0, target.1 - missile.1);
+    let time = -offset.dot(closing) / closing.dot(closing);
+    assert!(time < 2.0 * TERMINAL_TIME, "handed over {time}s out");
+    assert!((offset + closing * time).length() <= COAST_MISS);
+}
+
+#[test]
+fn torpedoes_take_longer_than_missiles() {
+    let (origin, still, target) = (vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(4000.0, 0.0));
+    let missile = MissileEnergy::for_class(Class::Missile).flight_time(origin, still, target, still).unwrap();
+    let torpedo = MissileEnergy::for_class(Class::Torpedo).flight_time(origin, still, target, still).unwrap();
+    assert!((missile - (2.0 * 4000.0 / 300.0_f64).sqrt()).abs() < 0.05, "{missile}");
+    assert!(torpedo > 2.0 * missile, "{torpedo}");
+    assert_eq!(
+        MissileEnergy::for_class(Class::Torpedo).flight_time(origin, still, vec2(40000.0, 0.0), still),
+        None
+    );
+}
+
+#[test]
+fn sitting_on_the_target_burns_nothing() {
+    let (position, velocity) = (vec2(1000.0, -500.0), vec2(50.0, 20.0));
+    let plan = energy(2000.0).plan(position, velocity, position, velocity, vec2(0.0, 0.0)).unwrap();
+    assert_eq!((plan.delta_v, plan.direction), (0.0, vec2(0.0, 0.0)));
+    assert_eq!(plan.burn(300.0), vec2(0.0, 0.0));
+    assert!(energy(2000.0).no_escape(position, velocity, position, velocity, Class::Fighter));
+}
@@ -0,0 +1,86 @@
+/**************************************************************
+* Proximity fuse tests
+* Closest approach worked by hand, arming from the predicted
+* pass, and the fuse flown tick by tick past targets inside and
+* outside the blast radius
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::fuse::{ClosestApproach, ProximityFuse, ARMING_TIME, FUSE_DELAY};
+
+const DT: f64 = 1.0 / 60.0;
+
+// Fly the missile past a stationary target, returning the distance the warhead went
+// off at and the closest any tick came
+fn pass(start: Vec2, velocity: Vec2) -> (Option<f64>, f64) {
+    let mut fuse = ProximityFuse::create();
+    let target = vec2(0.0, 0.0);
+    let mut closest = f64::INFINITY;
+    for tick in 0..600 {
+        let missile = start + velocity * (tick as f64 * DT);
+        closest = closest.min((target - missile).length());
+        if fuse.update(missile, velocity, target, vec2(0.0, 0.0)) {
+            return (Some((target - (missile + velocity * FUSE_DELAY)).length()), closest);
+        }
+    }
+    (None, closest)
+}
+
+#[test]
+fn closest_approach_of_a_crossing_pass() {
+    let approach = ClosestApproach::create(vec2(1000.0, 50.0), vec2(-500.0, 0.0));
+    assert_eq!(approach, ClosestApproach { time: 2.0, miss: 50.0 });
+    let opening = ClosestApproach::create(vec2(100.0, 0.0), vec2(10.0, 0.0));
+    assert_eq!(opening, ClosestApproach { time: 0.0, miss: 100.0 });
+    assert_eq!(ClosestApproach::create(vec2(30.0, 40.0), vec2(0.0, 0.0)).miss, 50.0);
+}
+
+#[test]
+fn fires_on_the_closest_tick() {
+    for (start, velocity) in [
+        (vec2(-3000.0, 80.0), vec2(900.0, 0.0)),
+        (vec2(-2000.0, -1990.0), vec2(700.0, 700.0)),
+        (vec2(300.0, 3000.0), vec2(-100.0, -1200.0)),
+    ] {
+        let (detonated, closest) = pass(start, velocity);
+        let detonated = detonated.expect("inside the blast radius");
+        // the tick it goes off on is as close as any tick gets
+        assert!(detonated <= closest + 1e-9, "{detonated} against {closest}");
+    }
+}
+
+#[test]
+fn holds_fire_on_a_pass_outside_the_blast_radius() {
+    assert_eq!(pass(vec2(-3000.0, 200.0), vec2(900.0, 0.0)).0, None);
+}
+
+#[test]
+fn fires_at_once_when_the_target_is_already_opening() {
+    let mut fuse = ProximityFuse::create();
+    assert!(fuse.update(vec2(0.0, 0.0), vec2(500.0, 0.0), vec2(-100.0, 20.0), vec2(0.0, 0.0)));
+    assert_eq!(fuse.predicted_miss(), Some(100.0_f64.hypot(20.0)));
+}
+
+#[test]
+fn reports_the_predicted_miss_while_closing() {
+    let mut fuse = ProximityFuse::create().with_blast_radius(50.0);
+    assert!(!fuse.update(vec2(0.0, 0.0), vec2(1000.0, 0.0), vec2(5000.0, 30.0), vec2(0.0, 0.0)));
+    let approach = fuse.approach.unwrap();
+    assert!((approach.time - 5.0).abs() < 1e-12);
+    assert!((approach.miss - 30.0).abs() < 1e-12);
+}
+
+#[test]
+fn arms_from_the_predicted_pass_not_the_range() {
+    let mut fuse = ProximityFuse::create();
+    assert!(!fuse.is_armed(), "nothing predicted yet");
+    // 400m out but closing at 1000 m/s, the pass is 0.4s away
+    fuse.update(vec2(0.0, 0.0), vec2(1000.0, 0.0), vec2(400.0, 60.0), vec2(0.0, 0.0));
+    assert!(fuse.is_armed());
+    // 150m out closing at 100 m/s is 1.5s away
+    fuse.update(vec2(0.0, 0.0), vec2(100.0, 0.0), vec2(150.0, 0.0), vec2(0.0, 0.0));
+    assert!(!fuse.is_armed());
+    // chasing a target that is pulling away
+    fuse.update(vec2(0.0, 0.0), vec2(100.0, 0.0), vec2(150.0, 0.0), vec2(200.0, 0.0));
+    assert!(!fuse.is_armed());
+    assert!(ProximityFuse::create().with_arming_time(2.0).arming_time > ARMING_TIME);
+}
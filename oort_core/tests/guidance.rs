@@ -0,0 +1,107 @@
+/**************************************************************
+* Guidance tests
+* Each PN law against hand worked geometry, and flown closed
+* loop against crossing and jinking targets
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::guidance::{Guidance, LineOfSight, PnLaw};
+
+const LAWS: [PnLaw; 3] = [PnLaw::Pure, PnLaw::True, PnLaw::Augmented];
+const DT: f64 = 1.0 / 60.0;
+
+#[test]
+fn collision_course_needs_no_correction() {
+    // both heading for the same point at the same time
+    let missile_velocity = vec2(500.0, 0.0);
+    let target_velocity = vec2(0.0, -250.0);
+    for law in LAWS {
+        let command = Guidance::create(law).command(vec2(0.0, 0.0), missile_velocity, vec2(2000.0, 1000.0), target_velocity, vec2(0.0, 0.0));
+        assert!(command.length() < 1e-9, "{law:?} commanded {command}");
+    }
+}
+
+#[test]
+fn line_of_sight_rate_and_closing_speed() {
+    let sight = LineOfSight::create(vec2(1000.0, 0.0), vec2(-300.0, 100.0)).unwrap();
+    assert_eq!(sight.direction, vec2(1.0, 0.0));
+    assert!((sight.rate - 0.1).abs() < 1e-12);
+    assert!((sight.closing_speed - 300.0).abs() < 1e-12);
+    assert_eq!(LineOfSight::create(vec2(0.0, 0.0), vec2(1.0, 0.0)), None);
+}
+
+#[test]
+fn laws_steer_across_the_line_of_sight() {
+    // target crossing to the left at 100 m/s, 1000 m ahead, closing at 300 m/s
+    let (missile, missile_velocity) = (vec2(0.0, 0.0), vec2(300.0, 0.0));
+    let (target, target_velocity) = (vec2(1000.0, 0.0), vec2(0.0, 100.0));
+    let guidance = |law| Guidance::create(law).with_navigation_constant(3.0).with_max_acceleration(1000.0);
+
+    let pure = guidance(PnLaw::Pure).command(missile, missile_velocity, target, target_velocity, vec2(0.0, 0.0));
+    assert!((pure - vec2(0.0, 3.0 * 0.1 * 300.0)).length() < 1e-9);
+    let true_pn = guidance(PnLaw::True).command(missile, missile_velocity, target, target_velocity, vec2(0.0, 0.0));
+    assert!((true_pn - vec2(0.0, 90.0)).length() < 1e-9);
+    let augmented = guidance(PnLaw::Augmented).command(missile, missile_velocity, target, target_velocity, vec2(50.0, 20.0));
+    assert!(
+        (augmented - vec2(0.0, 90.0 + 1.5 * 20.0)).length() < 1e-9,
+        "only the target acceleration across the sight counts"
+    );
+}
+
+#[test]
+fn commands_are_limited() {
+    let guidance = Guidance::create(PnLaw::True).with_max_acceleration(100.0);
+    let command = guidance.command(vec2(0.0, 0.0), vec2(500.0, 0.0), vec2(500.0, 0.0), vec2(0.0, 800.0), vec2(0.0, 0.0));
+    assert!((command.length() - 100.0).abs() < 1e-9);
+    let thrust = guidance.thrust(vec2(0.0, 0.0), vec2(500.0, 0.0), vec2(500.0, 0.0), vec2(0.0, 800.0), vec2(0.0, 0.0));
+    assert!((thrust.length() - 100.0).abs() < 1e-9, "nothing spare once the turn takes it all");
+
+    let easy = guidance.thrust(vec2(0.0, 0.0), vec2(500.0, 0.0), vec2(5000.0, 0.0), vec2(0.0, 0.0), vec2(0.0, 0.0));
+    assert!((easy - vec2(100.0, 0.0)).length() < 1e-9, "spare acceleration closes along the sight");
+}
+
+// Closest approach of a missile flying `guidance` against a target pulling a constant
+// `target_acceleration`, and the largest turn the PN law asked for on the way in
+fn fly(guidance: Guidance, target_velocity: Vec2, target_acceleration: Vec2) -> (f64, f64) {
+    let (mut missile, mut missile_velocity) = (vec2(0.0, 0.0), vec2(400.0, 0.0));
+    let (mut target, mut target_velocity) = (vec2(5000.0, -1500.0), target_velocity);
+    let (mut closest, mut peak) = (f64::INFINITY, 0.0f64);
+    for _ in 0..60 * 30 {
+        peak = peak.max(
+            guidance
+                .command(missile, missile_velocity, target, target_velocity, target_acceleration)
+                .length(),
+        );
+        let command = guidance.thrust(missile, missile_velocity, target, target_velocity, target_acceleration);
+        missile_velocity += command * DT;
+        target_velocity += target_acceleration * DT;
+        // closest approach along this tick's straight line relative motion
+        let (offset, closing) = (target - missile, target_velocity - missile_velocity);
+        let t = (-offset.dot(closing) / closing.dot(closing).max(f64::EPSILON)).clamp(0.0, DT);
+        closest = closest.min((offset + closing * t).length());
+        missile += missile_velocity * DT;
+        target += target_velocity * DT;
+        if t < DT && closest < 1000.0 {
+            break; // past the closest approach
+        }
+    }
+    (closest, peak)
+}
+
+#[test]
+fn every_law_hits_a_crossing_target() {
+    for law in LAWS {
+        let (miss, _) = fly(Guidance::create(law), vec2(0.0, 200.0), vec2(0.0, 0.0));
+        assert!(miss < 5.0, "{law:?} missed by {miss}");
+    }
+}
+
+#[test]
+fn augmented_pn_turns_less_against_a_turning_target() {
+    for target_acceleration in [vec2(-40.0, 0.0), vec2(-60.0, 0.0)] {
+        let guidance = |law| Guidance::create(law).with_max_acceleration(100.0);
+        let (true_miss, true_peak) = fly(guidance(PnLaw::True), vec2(0.0, 200.0), target_acceleration);
+        let (augmented_miss, augmented_peak) = fly(guidance(PnLaw::Augmented), vec2(0.0, 200.0), target_acceleration);
+        assert!(true_miss < 5.0 && augmented_miss < 5.0, "true {true_miss}, augmented {augmented_miss}");
+        assert!(augmented_peak < true_peak / 2.0, "augmented {augmented_peak} against true {true_peak}");
+    }
+}
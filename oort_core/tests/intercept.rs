@@ -0,0 +1,118 @@
+/**************************************************************
+* Intercept tests
+* The quadratic time of flight against hand-worked geometry,
+* the cases where no intercept exists, and the iterative
+* solver for accelerating targets
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::intercept::{intercept_point, intercept_time, solve_intercept, Projectile};
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn stationary_target_is_range_over_speed() {
+    let time = intercept_time(vec2(3000.0, 4000.0), vec2(0.0, 0.0), 1000.0).unwrap();
+    assert!(close(time, 5.0));
+}
+
+#[test]
+fn crossing_target_uses_the_intercept_range() {
+    // 3-4-5 triangle: the target moves 600 m/s across a 800 m gap, the bullet covers 1000 m/s of hypotenuse
+    let time = intercept_time(vec2(800.0, 0.0), vec2(0.0, 600.0), 1000.0).unwrap();
+    assert!(close(time, 1.0), "current range alone would give 0.8s");
+    let aim = intercept_point(vec2(0.0, 0.0), vec2(0.0, 0.0), vec2(800.0, 0.0), vec2(0.0, 600.0), 1000.0).unwrap();
+    assert!(close(aim.x, 800.0) && close(aim.y, 600.0));
+}
+
+#[test]
+fn shooter_velocity_is_relative() {
+    let aim = intercept_point(vec2(0.0, 0.0), vec2(0.0, 600.0), vec2(1000.0, 0.0), vec2(0.0, 600.0), 1000.0).unwrap();
+    assert!(close(aim.x, 1000.0) && close(aim.y, 0.0), "flying in formation needs no lead");
+}
+
+#[test]
+fn target_as_fast_as_the_projectile() {
+    assert!(close(intercept_time(vec2(1000.0, 0.0), vec2(-1000.0, 0.0), 1000.0).unwrap(), 0.5));
+    assert_eq!(
+        intercept_time(vec2(1000.0, 0.0), vec2(1000.0, 0.0), 1000.0),
+        None,
+        "running away at bullet speed"
+    );
+}
+
+#[test]
+fn faster_receding_target_has_no_intercept() {
+    assert_eq!(intercept_time(vec2(1000.0, 0.0), vec2(1500.0, 0.0), 1000.0), None);
+    assert_eq!(intercept_time(vec2(1000.0, 0.0), vec2(0.0, 1500.0), 1000.0), None);
+}
+
+#[test]
+fn faster_closing_target_takes_the_earliest_meeting() {
+    let time = intercept_time(vec2(1000.0, 0.0), vec2(-1500.0, 0.0), 1000.0).unwrap();
+    assert!(close(time, 0.4));
+}
+
+#[test]
+fn solver_matches_the_quadratic_without_acceleration() {
+    let solution = solve_intercept(
+        vec2(0.0, 0.0),
+        vec2(0.0, 0.0),
+        vec2(800.0, 0.0),
+        vec2(0.0, 600.0),
+        vec2(0.0, 0.0),
+        &Projectile::bullet(),
+    )
+    .unwrap();
+    assert!(close(solution.time, 1.0));
+    assert!(close(solution.aim_point.x, 800.0) && close(solution.aim_point.y, 600.0));
+    assert!(solution.miss_distance < 1e-6);
+}
+
+#[test]
+fn solver_leads_an_accelerating_target() {
+    let (target, velocity, acceleration) = (vec2(1500.0, 0.0), vec2(0.0, 100.0), vec2(0.0, 60.0));
+    let solution = solve_intercept(vec2(0.0, 0.0), vec2(0.0, 0.0), target, velocity, acceleration, &Projectile::bullet()).unwrap();
+    let time = solution.time;
+    let meeting = target + velocity * time + acceleration * (0.5 * time * time);
+    assert!((meeting - solution.aim_point).length() < 1e-6);
+    assert!(solution.miss_distance < 0.1, "residual {}", solution.miss_distance);
+    let bullet = solution.aim_point.normalize() * 1000.0 * time;
+    assert!((bullet - meeting).length() < 0.1);
+}
+
+#[test]
+fn inherited_velocity_is_part_of_the_projectile() {
+    let target = vec2(1000.0, 0.0);
+    let shooter_velocity = vec2(0.0, 200.0);
+    let inherited = solve_intercept(
+        vec2(0.0, 0.0),
+        shooter_velocity,
+        target,
+        vec2(0.0, 0.0),
+        vec2(0.0, 0.0),
+        &Projectile::create(1000.0),
+    )
+    .unwrap();
+    let own_speed = Projectile::create(1000.0).without_inherited_velocity();
+    let self_propelled = solve_intercept(vec2(0.0, 0.0), shooter_velocity, target, vec2(0.0, 0.0), vec2(0.0, 0.0), &own_speed).unwrap();
+    assert!(inherited.aim_point.y < 0.0, "aim behind to cancel our drift");
+    assert!(close(self_propelled.aim_point.y, 0.0));
+    assert!(close(self_propelled.time, 1.0));
+}
+
+#[test]
+fn expired_projectile_reports_its_shortfall() {
+    let solution = solve_intercept(
+        vec2(0.0, 0.0),
+        vec2(0.0, 0.0),
+        vec2(7000.0, 0.0),
+        vec2(0.0, 0.0),
+        vec2(0.0, 0.0),
+        &Projectile::bullet(),
+    )
+    .unwrap();
+    assert!(close(solution.time, 7.0));
+    assert!((solution.miss_distance - 2000.0).abs() < 1e-6, "a 5s bullet stops 2km short");
+}
@@ -0,0 +1,93 @@
+/**************************************************************
+* Kalman tests
+* Feeds the contact filter noisy measurements of a target with
+* known motion and checks what it recovers
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::kalman::{ContactFilter, MeasurementNoise};
+
+// Deterministic gaussian-ish noise, sum of uniforms from an LCG
+struct Noise(u64);
+
+impl Noise {
+    fn next(&mut self) -> f64 {
+        let mut sum = 0.0;
+        for _ in 0..12 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            sum += (self.0 >> 11) as f64 / (1u64 << 53) as f64;
+        }
+        sum - 6.0
+    }
+
+    fn vector(&mut self, sigma: f64) -> Vec2 {
+        vec2(self.next(), self.next()) * sigma
+    }
+}
+
+fn truth(time: f64) -> (Vec2, Vec2) {
+    let (start, velocity, acceleration) = (vec2(1000.0, -500.0), vec2(100.0, 50.0), vec2(-20.0, 30.0));
+    (start + velocity * time + acceleration * (0.5 * time * time), velocity + acceleration * time)
+}
+
+#[test]
+fn recovers_constant_acceleration_from_noisy_scans() {
+    let noise = MeasurementNoise::create(20.0, 10.0);
+    let mut random = Noise(7);
+    let (position, velocity) = truth(0.0);
+    let mut filter = ContactFilter::create(position, velocity, noise, 0.0);
+    for tick in 1..=300 {
+        let time = tick as f64 * TICK_LENGTH;
+        let (position, velocity) = truth(time);
+        filter.update(position + random.vector(noise.position), velocity + random.vector(noise.velocity), noise, time);
+    }
+    let (position, velocity) = truth(5.0);
+    assert!(
+        (filter.position() - position).length() < noise.position / 2.0,
+        "position {}",
+        filter.position() - position
+    );
+    assert!(
+        (filter.velocity() - velocity).length() < noise.velocity / 2.0,
+        "velocity {}",
+        filter.velocity() - velocity
+    );
+    assert!(
+        (filter.acceleration() - vec2(-20.0, 30.0)).length() < 5.0,
+        "acceleration {}",
+        filter.acceleration()
+    );
+}
+
+#[test]
+fn covariance_shrinks_as_measurements_arrive() {
+    let noise = MeasurementNoise::create(20.0, 10.0);
+    let mut filter = ContactFilter::create(vec2(0.0, 0.0), vec2(0.0, 0.0), noise, 0.0);
+    let initial = filter.acceleration_variance();
+    for tick in 1..=60 {
+        filter.update(vec2(0.0, 0.0), vec2(0.0, 0.0), noise, tick as f64 * TICK_LENGTH);
+    }
+    assert!(filter.position_variance().x < noise.position * noise.position);
+    assert!(filter.acceleration_variance().x < initial.x / 10.0);
+}
+
+#[test]
+fn prediction_grows_uncertainty() {
+    let mut filter = ContactFilter::create(vec2(0.0, 0.0), vec2(100.0, 0.0), MeasurementNoise::exact(), 0.0);
+    let before = filter.position_variance();
+    filter.predict(1.0);
+    assert!((filter.position() - vec2(100.0, 0.0)).length() < 1e-9);
+    assert!(filter.position_variance().x > before.x);
+    assert_eq!(filter.time, 1.0);
+}
+
+#[test]
+fn weak_returns_are_trusted_less() {
+    let strong = MeasurementNoise::from_snr(5000.0, TAU / 60.0, 30.0);
+    let weak = MeasurementNoise::from_snr(5000.0, TAU / 60.0, 5.0);
+    assert!(weak.position > strong.position * 10.0);
+    let start = ContactFilter::create(vec2(0.0, 0.0), vec2(0.0, 0.0), MeasurementNoise::create(50.0, 25.0), 0.0);
+    let (mut trusting, mut doubting) = (start, start);
+    trusting.update(vec2(100.0, 0.0), vec2(0.0, 0.0), strong, TICK_LENGTH);
+    doubting.update(vec2(100.0, 0.0), vec2(0.0, 0.0), weak, TICK_LENGTH);
+    assert!(trusting.position().x > doubting.position().x);
+}
@@ -0,0 +1,117 @@
+/**************************************************************
+* Navigation tests
+* Thrust kept inside the limits at every heading, and the
+* motion planner flown tick by tick onto still and moving
+* goals from every side of the hull without overshooting
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::navigation::{MotionPlanner, ThrustLimits};
+
+const DT: f64 = 1.0 / 60.0;
+const FIGHTER: ThrustLimits = ThrustLimits {
+    forward: 60.0,
+    backward: 30.0,
+    lateral: 30.0,
+};
+const FRIGATE: ThrustLimits = ThrustLimits {
+    forward: 10.0,
+    backward: 5.0,
+    lateral: 5.0,
+};
+
+// Fly onto a goal moving at goal_velocity with the heading held, returning the worst overshoot
+// past the goal along the approach and the ticks until the ship sat on it
+fn arrive(planner: MotionPlanner, heading: f64, start: Vec2, goal: Vec2, goal_velocity: Vec2) -> (f64, Option<usize>) {
+    let limits = planner.limits;
+    let approach = (goal - start).normalize();
+    let (mut position, mut velocity, mut goal) = (start, goal_velocity, goal);
+    let (mut overshoot, mut arrived) = (0.0_f64, None);
+    for tick in 0..60 * 120 {
+        let acceleration = planner.acceleration(position, velocity, heading, goal, goal_velocity);
+        let local = acceleration.rotate(-heading);
+        assert!(local.x <= limits.forward + 1e-9 && -local.x <= limits.backward + 1e-9 && local.y.abs() <= limits.lateral + 1e-9);
+        velocity += acceleration * DT;
+        position += velocity * DT;
+        goal += goal_velocity * DT;
+        overshoot = overshoot.max((position - goal).dot(approach));
+        let on_goal = (position - goal).length() < 0.5 && (velocity - goal_velocity).length() < 0.5;
+        arrived = match (on_goal, arrived) {
+            (true, None) => Some(tick + 1),
+            (true, at) => at,
+            (false, _) => None,
+        };
+    }
+    (overshoot, arrived)
+}
+
+#[test]
+fn sitting_on_the_goal_only_matches_its_velocity() {
+    let planner = MotionPlanner::create(FIGHTER);
+    let goal = vec2(100.0, -50.0);
+    assert_eq!(planner.acceleration(goal, vec2(0.0, 0.0), 0.0, goal, vec2(0.0, 0.0)), vec2(0.0, 0.0));
+    let acceleration = planner.acceleration(goal, vec2(0.0, 0.0), 0.0, goal, vec2(0.1, 0.0));
+    assert!((acceleration - vec2(6.0, 0.0)).length() < 1e-9, "{acceleration:?}");
+}
+
+#[test]
+fn clip_saturates_each_axis() {
+    assert_eq!(FIGHTER.clip(vec2(500.0, -500.0), 0.0), vec2(60.0, -30.0));
+    assert_eq!(FIGHTER.clip(vec2(-500.0, 10.0), 0.0), vec2(-30.0, 10.0));
+}
+
+#[test]
+fn clamp_keeps_the_direction() {
+    // straight back from a fighter facing +x can only take 30
+    assert_eq!(FIGHTER.clamp(vec2(-90.0, 0.0), 0.0), vec2(-30.0, 0.0));
+    // forward and to the side scales both down together
+    let clamped = FIGHTER.clamp(vec2(120.0, 30.0), 0.0);
+    assert!((clamped - vec2(60.0, 15.0)).length() < 1e-12, "{clamped:?}");
+    // facing +y the lateral limit applies along x
+    let clamped = FIGHTER.clamp(vec2(100.0, 0.0), PI / 2.0);
+    assert!((clamped - vec2(30.0, 0.0)).length() < 1e-9, "{clamped:?}");
+    assert_eq!(FIGHTER.clamp(vec2(10.0, 10.0), 0.0), vec2(10.0, 10.0));
+}
+
+#[test]
+fn arrives_without_overshoot_from_every_side() {
+    for limits in [FIGHTER, FRIGATE] {
+        for side in 0..8 {
+            let start = vec2(2000.0, 0.0).rotate(side as f64 * TAU / 8.0);
+            let (overshoot, arrived) = arrive(MotionPlanner::create(limits), 0.3, start, vec2(0.0, 0.0), vec2(0.0, 0.0));
+            assert!(overshoot < 0.5, "{limits:?} from {start}: {overshoot}m past");
+            assert!(arrived.is_some(), "{limits:?} from {start} never arrived");
+        }
+    }
+}
+
+#[test]
+fn full_thrust_arrives_no_later_and_without_overshoot() {
+    for limits in [FIGHTER, FRIGATE] {
+        for side in 0..8 {
+            let start = vec2(2000.0, 0.0).rotate(side as f64 * TAU / 8.0);
+            let (_, kept) = arrive(MotionPlanner::create(limits), 0.3, start, vec2(0.0, 0.0), vec2(0.0, 0.0));
+            let (overshoot, arrived) = arrive(MotionPlanner::create(limits).with_full_thrust(), 0.3, start, vec2(0.0, 0.0), vec2(0.0, 0.0));
+            assert!(overshoot < 0.5, "{limits:?} from {start}: {overshoot}m past");
+            assert!(arrived.unwrap() <= kept.unwrap(), "{limits:?} from {start}: {arrived:?} after {kept:?}");
+        }
+    }
+}
+
+#[test]
+fn matches_a_moving_goal() {
+    let (overshoot, arrived) = arrive(MotionPlanner::create(FIGHTER), 0.0, vec2(0.0, 0.0), vec2(3000.0, 1000.0), vec2(-50.0, 80.0));
+    assert!(overshoot < 0.5, "{overshoot}m past");
+    assert!(arrived.is_some());
+}
+
+#[test]
+fn max_speed_caps_the_closing_speed() {
+    let planner = MotionPlanner::create(FIGHTER).with_max_speed(100.0);
+    let (mut position, mut velocity) = (vec2(0.0, 0.0), vec2(0.0, 0.0));
+    for _ in 0..60 * 10 {
+        velocity += planner.acceleration(position, velocity, 0.0, vec2(5000.0, 0.0), vec2(0.0, 0.0)) * DT;
+        position += velocity * DT;
+        assert!(velocity.length() <= 100.0 + 1e-9);
+    }
+    assert!((velocity.length() - 100.0).abs() < 1e-9);
+}
@@ -0,0 +1,153 @@
+/**************************************************************
+* Radar scheduler tests
+* Which task the scheduler gives each tick, and where it
+* points the beam in the mock environment
+****************************************************************/
+use oort_api::mock::{self, Environment};
+use oort_api::prelude::*;
+use oort_core::kalman::MeasurementNoise;
+use oort_core::radar::{revisit_priority, RadarData, RadarScheduler, RadarState, RadarTask};
+use oort_core::tracking::TrackTable;
+
+fn install_frigate() {
+    mock::install(Environment::for_class(Class::Frigate));
+}
+
+fn next_tick() {
+    mock::with(|env| env.tick += 1);
+}
+
+fn radar() -> mock::Radar {
+    mock::environment().radar
+}
+
+// A confirmed fighter track last seen `age` seconds before now
+fn stale_track(table: &mut TrackTable, class: Class, position: Vec2, age: f64) {
+    let now = current_time();
+    for tick in 0..3 {
+        table.ingest_measurement(
+            class,
+            position,
+            vec2(0.0, 0.0),
+            MeasurementNoise::create(10.0, 5.0),
+            now - age + tick as f64 * TICK_LENGTH,
+        );
+    }
+}
+
+#[test]
+fn empty_sky_is_swept() {
+    install_frigate();
+    let mut scheduler = RadarScheduler::create().with_sweep(TAU / 32.0, 10000.0);
+    let tracks = TrackTable::create();
+    let mut headings = Vec::new();
+    for _ in 0..4 {
+        assert_eq!(scheduler.plan(&tracks), RadarTask::Sweep);
+        headings.push(radar().heading);
+        next_tick();
+    }
+    for pair in headings.windows(2) {
+        assert!((angle_diff(pair[0], pair[1]) - TAU / 32.0).abs() < 1e-9);
+    }
+    assert_eq!(radar().max_distance, 10000.0);
+}
+
+#[test]
+fn sector_sweep_turns_around_at_the_edges() {
+    install_frigate();
+    let mut scheduler = RadarScheduler::create().with_sweep(0.1, 10000.0).with_sector(0.0, 0.35);
+    let tracks = TrackTable::create();
+    for _ in 0..20 {
+        scheduler.plan(&tracks);
+        let heading = radar().heading.rem_euclid(TAU);
+        assert!(heading <= 0.35 + 1e-9, "heading {} left the sector", heading);
+        next_tick();
+    }
+}
+
+#[test]
+fn point_of_interest_gets_a_single_dwell() {
+    install_frigate();
+    let mut scheduler = RadarScheduler::create().with_sweep_share(3);
+    let mut tracks = TrackTable::create();
+    scheduler.add_point_of_interest(vec2(0.0, 5000.0), 200.0, 2.0);
+    assert_eq!(scheduler.plan(&tracks), RadarTask::Dwell(vec2(0.0, 5000.0)));
+    let beam = radar();
+    assert!((beam.heading - PI / 2.0).abs() < 1e-9);
+    assert!(beam.min_distance <= 4800.0 && beam.max_distance >= 5200.0);
+    next_tick();
+    scheduler.observe(&mut tracks, None);
+    assert!(scheduler.points_of_interest.is_empty());
+}
+
+#[test]
+fn every_third_tick_is_a_sweep() {
+    install_frigate();
+    let mut scheduler = RadarScheduler::create().with_sweep_share(3);
+    let tracks = TrackTable::create();
+    for index in 0..6 {
+        scheduler.add_point_of_interest(vec2(1000.0 * (index + 1) as f64, 0.0), 100.0, 10.0);
+    }
+    let tasks: Vec<RadarTask> = (0..6)
+        .map(|_| {
+            let task = scheduler.plan(&tracks);
+            if let RadarTask::Dwell(point) = task {
+                scheduler.points_of_interest.retain(|poi| poi.position != point);
+            }
+            next_tick();
+            task
+        })
+        .collect();
+    assert_eq!(tasks.iter().filter(|task| **task == RadarTask::Sweep).count(), 2);
+    assert_eq!(tasks[2], RadarTask::Sweep);
+}
+
+#[test]
+fn stale_tracks_are_revisited() {
+    install_frigate();
+    mock::with(|env| env.tick = 600);
+    let mut scheduler = RadarScheduler::create();
+    let mut tracks = TrackTable::create().with_timeouts(1.0, 10.0);
+    stale_track(&mut tracks, Class::Fighter, vec2(8000.0, 0.0), 3.0);
+    tracks.update(current_time());
+    let id = tracks.tracks[0].id;
+    assert_eq!(scheduler.plan(&tracks), RadarTask::Revisit(id));
+    assert!(radar().heading.abs() < 0.01);
+}
+
+#[test]
+fn fresh_tracks_leave_the_radar_to_the_sweep() {
+    install_frigate();
+    mock::with(|env| env.tick = 600);
+    let mut scheduler = RadarScheduler::create();
+    let mut tracks = TrackTable::create();
+    stale_track(&mut tracks, Class::Frigate, vec2(8000.0, 0.0), 0.05);
+    assert_eq!(scheduler.plan(&tracks), RadarTask::Sweep);
+}
+
+#[test]
+fn missiles_outrank_ships_at_equal_uncertainty() {
+    install_frigate();
+    mock::with(|env| env.tick = 600);
+    let mut tracks = TrackTable::create();
+    stale_track(&mut tracks, Class::Frigate, vec2(5000.0, 0.0), 1.0);
+    stale_track(&mut tracks, Class::Missile, vec2(0.0, 5000.0), 1.0);
+    let now = current_time();
+    let frigate = revisit_priority(&tracks.tracks[0], now, TAU / 120.0);
+    let missile = revisit_priority(&tracks.tracks[1], now, TAU / 120.0);
+    assert!(missile > frigate * 2.0);
+}
+
+#[test]
+fn poi_scan_points_at_the_lost_contact() {
+    install_frigate();
+    let mut radar_data = RadarData::create();
+    for _ in 0..5 {
+        radar_data.update_poi_scan(vec2(0.0, -3000.0), 150.0);
+    }
+    assert_eq!(radar_data.radar_state, RadarState::POIScan);
+    assert!(angle_diff(radar().heading, -PI / 2.0).abs() < 1e-9);
+    assert!(radar().width < TAU / 16.0);
+    assert!(!radar_data.poi_expired(6));
+    assert!(radar_data.poi_expired(5));
+}
@@ -0,0 +1,252 @@
+/**************************************************************
+* Radio tests
+* Typed messages survive the four f64 slots of a Message and
+* foreign messages are turned away. The radio manager spreads
+* purposes over radios and queues what it can not send yet,
+* and the fleet picture shares and merges track tables
+****************************************************************/
+use oort_api::mock::{self, Environment};
+use oort_api::prelude::*;
+use oort_core::kalman::MeasurementNoise;
+use oort_core::radio::{
+    receive_message, send_message, FleetPicture, MessageID, RadioManager, RadioMessage, RadioPurpose, COMMAND_CHANNEL, MAX_QUEUED_MESSAGES, MISSILE_CHANNEL,
+    MISSILE_RADIO, POSITIONING_CHANNEL, POSITION_RESOLUTION, VELOCITY_RESOLUTION,
+};
+use oort_core::tracking::TrackTable;
+
+fn message(id: MessageID, sender: u32, track: u32, position: Vec2, velocity: Vec2, tick: u32) -> RadioMessage {
+    RadioMessage {
+        id,
+        sender,
+        track,
+        position,
+        velocity,
+        class: Class::Unknown,
+        tick,
+    }
+}
+
+#[test]
+fn round_trips_every_kind_losslessly() {
+    for id in [
+        MessageID::Contact,
+        MessageID::TargetUpdate,
+        MessageID::TrackLost,
+        MessageID::Position,
+        MessageID::Assignment,
+    ] {
+        let original = message(id, 1_234_567, 98_765_432, vec2(-15000.25, 19999.984375), vec2(-850.5, 0.0009765625), 86_400);
+        assert_eq!(RadioMessage::decode(original.encode()), Some(original));
+    }
+}
+
+#[test]
+fn round_trips_every_class() {
+    let classes = [
+        Class::Fighter,
+        Class::Frigate,
+        Class::Cruiser,
+        Class::Asteroid,
+        Class::Target,
+        Class::Missile,
+        Class::Torpedo,
+        Class::Unknown,
+    ];
+    for class in classes {
+        let original = message(MessageID::Contact, 1, 2, vec2(10.0, 20.0), vec2(1.0, 2.0), u32::MAX);
+        let original = RadioMessage { class, ..original };
+        assert_eq!(RadioMessage::decode(original.encode()), Some(original));
+    }
+}
+
+#[test]
+fn field_extremes_round_trip() {
+    let largest = message(
+        MessageID::Position,
+        (1 << 21) - 1,
+        (1 << 27) - 1,
+        vec2(-524288.0, 524287.984375),
+        vec2(32767.0, -32768.0),
+        u32::MAX,
+    );
+    assert_eq!(RadioMessage::decode(largest.encode()), Some(largest));
+    let smallest = message(MessageID::Contact, 0, 0, vec2(0.0, 0.0), vec2(0.0, 0.0), 0);
+    assert_eq!(RadioMessage::decode(smallest.encode()), Some(smallest));
+}
+
+#[test]
+fn off_grid_values_round_to_the_resolution() {
+    let original = message(MessageID::Contact, 7, 3, vec2(1234.5678, -9876.54321), vec2(123.456789, -0.0001), 10);
+    let decoded = RadioMessage::decode(original.encode()).unwrap();
+    assert!((decoded.position - original.position).length() <= POSITION_RESOLUTION);
+    assert!((decoded.velocity - original.velocity).length() <= VELOCITY_RESOLUTION);
+    assert_eq!(RadioMessage::decode(decoded.encode()), Some(decoded), "decoding is stable once on the grid");
+}
+
+#[test]
+fn every_slot_is_an_exact_integer() {
+    let encoded = message(MessageID::TrackLost, 42, 17, vec2(-1.0, 1.0), vec2(-300.0, 300.0), 99).encode();
+    assert!(encoded.iter().all(|slot| *slot >= 0.0 && slot.fract() == 0.0 && *slot < 2f64.powi(53)));
+}
+
+#[test]
+fn untyped_contacts_are_not_messages() {
+    assert_eq!(RadioMessage::decode([1000.0, 2000.0, 10.0, 0.0]), None);
+    assert_eq!(RadioMessage::decode([-1000.0, 2000.0, -10.0, 5.5]), None);
+    assert_eq!(RadioMessage::decode([0.0, 0.0, 0.0, 0.0]), None, "kind 0 is unused");
+    assert_eq!(RadioMessage::decode([f64::NAN, 0.0, 0.0, 0.0]), None);
+}
+
+#[test]
+fn create_stamps_sender_and_tick() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.id = 12;
+    env.tick = 300;
+    mock::install(env);
+    let sent = RadioMessage::create(MessageID::Contact, 5, vec2(100.0, 0.0), vec2(60.0, 0.0));
+    assert_eq!((sent.sender, sent.tick), (12, 300));
+
+    send_message(POSITIONING_CHANNEL, &sent);
+    let transmission = mock::take_commands().sent[0];
+    assert_eq!(transmission.channel, POSITIONING_CHANNEL);
+    mock::with(|env| {
+        env.tick = 360;
+        env.radios[0].inbox = Some(transmission.message);
+    });
+    let received = receive_message(POSITIONING_CHANNEL).unwrap();
+    assert_eq!(received, sent);
+    assert_eq!(received.extrapolated_position(), vec2(160.0, 0.0), "a second old at 60 m/s");
+}
+
+const ALL_PURPOSES: [RadioPurpose; 3] = [RadioPurpose::FleetContacts, RadioPurpose::Command, RadioPurpose::MissileUplink];
+
+fn contact(track: u32) -> RadioMessage {
+    message(MessageID::Contact, 1, track, vec2(100.0, 0.0), vec2(0.0, 0.0), 0)
+}
+
+#[test]
+fn frigate_uplink_gets_the_missile_radio() {
+    mock::install(Environment::for_class(Class::Frigate));
+    let manager = RadioManager::for_class(Class::Frigate, &ALL_PURPOSES);
+    assert_eq!(manager.radio(RadioPurpose::FleetContacts), Some(0));
+    assert_eq!(manager.radio(RadioPurpose::Command), Some(1));
+    assert_eq!(manager.radio(RadioPurpose::MissileUplink), Some(MISSILE_RADIO));
+    let channels: Vec<usize> = mock::environment().radios.iter().map(|radio| radio.channel).collect();
+    assert_eq!(channels[..2], [POSITIONING_CHANNEL, COMMAND_CHANNEL]);
+    assert_eq!(channels[MISSILE_RADIO], MISSILE_CHANNEL);
+}
+
+#[test]
+fn fighter_shares_its_first_radio() {
+    mock::install(Environment::for_class(Class::Fighter));
+    let mut manager = RadioManager::for_class(Class::Fighter, &ALL_PURPOSES);
+    assert_eq!(manager.radio(RadioPurpose::FleetContacts), Some(0));
+    assert_eq!(manager.radio(RadioPurpose::Command), Some(0));
+    assert_eq!(manager.radio(RadioPurpose::MissileUplink), Some(1));
+    let mut heard = Vec::new();
+    for _ in 0..4 {
+        heard.push(mock::environment().radios[0].channel);
+        manager.transmit();
+    }
+    assert_eq!(
+        heard,
+        [POSITIONING_CHANNEL, COMMAND_CHANNEL, POSITIONING_CHANNEL, COMMAND_CHANNEL],
+        "takes turns listening"
+    );
+}
+
+#[test]
+fn busy_radio_queues_the_rest() {
+    mock::install(Environment::for_class(Class::Fighter));
+    let mut manager = RadioManager::for_class(Class::Fighter, &[RadioPurpose::FleetContacts, RadioPurpose::MissileUplink]);
+    manager.queue(RadioPurpose::FleetContacts, contact(1));
+    manager.queue(RadioPurpose::FleetContacts, contact(2));
+    manager.queue(RadioPurpose::MissileUplink, contact(3));
+
+    manager.transmit();
+    let sent = mock::take_commands().sent;
+    assert_eq!(sent.len(), 2, "one message per radio per tick");
+    assert_eq!((sent[0].radio, sent[0].channel), (0, POSITIONING_CHANNEL));
+    assert_eq!(RadioMessage::decode(sent[0].message).unwrap().track, 1);
+    assert_eq!((sent[1].radio, sent[1].channel), (1, MISSILE_CHANNEL));
+    assert_eq!(manager.queued(RadioPurpose::FleetContacts), 1);
+
+    manager.transmit();
+    let sent = mock::take_commands().sent;
+    assert_eq!(sent.len(), 1);
+    assert_eq!(RadioMessage::decode(sent[0].message).unwrap().track, 2);
+    assert_eq!(mock::environment().selected_radio, 0);
+}
+
+#[test]
+fn full_queue_drops_the_oldest() {
+    mock::install(Environment::for_class(Class::Missile));
+    let mut manager = RadioManager::for_class(Class::Missile, &[RadioPurpose::MissileUplink]);
+    for track in 0..MAX_QUEUED_MESSAGES as u32 + 2 {
+        manager.queue(RadioPurpose::MissileUplink, contact(track));
+    }
+    assert_eq!(manager.queued(RadioPurpose::MissileUplink), MAX_QUEUED_MESSAGES);
+    manager.transmit();
+    assert_eq!(RadioMessage::decode(mock::take_commands().sent[0].message).unwrap().track, 2);
+    manager.queue(RadioPurpose::Command, contact(99));
+    assert_eq!(manager.queued(RadioPurpose::Command), 0, "purposes without a radio are dropped");
+}
+
+#[test]
+fn listen_tags_messages_with_their_purpose() {
+    mock::install(Environment::for_class(Class::Frigate));
+    let mut manager = RadioManager::for_class(Class::Frigate, &ALL_PURPOSES);
+    mock::with(|env| {
+        env.radios[1].inbox = Some(contact(5).encode());
+        env.radios[MISSILE_RADIO].inbox = Some([1.0, 2.0, 3.0, 4.0]);
+    });
+    manager.listen();
+    assert_eq!(manager.receive(RadioPurpose::Command).map(|message| message.track), Some(5));
+    assert_eq!(manager.receive(RadioPurpose::FleetContacts), None);
+    assert_eq!(manager.receive(RadioPurpose::MissileUplink), None, "untyped messages are ignored");
+}
+
+#[test]
+fn fleet_picture_shares_local_tracks_in_turn() {
+    mock::install(Environment::for_class(Class::Fighter));
+    let mut tracks = TrackTable::create().with_confirmation(1);
+    let noise = MeasurementNoise::exact();
+    let first = tracks.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), noise, 0.0);
+    let second = tracks.ingest_measurement(Class::Cruiser, vec2(-3000.0, 0.0), vec2(0.0, 0.0), noise, 0.0);
+    tracks.ingest_report(Class::Frigate, vec2(0.0, 5000.0), vec2(0.0, 0.0), 0.0, 0.0);
+    let mut fleet = FleetPicture::create();
+    let shared: Vec<(u32, Class)> = (0..3)
+        .map(|_| fleet.next_report(&tracks, 0.0).map(|message| (message.track, message.class)).unwrap())
+        .collect();
+    assert_eq!(shared, [(first, Class::Fighter), (second, Class::Cruiser), (first, Class::Fighter)]);
+}
+
+#[test]
+fn fleet_picture_merges_reports_from_others() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.id = 7;
+    env.tick = 60;
+    mock::install(env);
+    let mut tracks = TrackTable::create();
+    let mut manager = RadioManager::for_class(Class::Fighter, &[RadioPurpose::FleetContacts]);
+    let fleet = FleetPicture::create();
+
+    let own = RadioMessage {
+        sender: 7,
+        ..message(MessageID::Contact, 7, 1, vec2(100.0, 0.0), vec2(0.0, 0.0), 60)
+    };
+    mock::with(|env| env.radios[0].inbox = Some(own.encode()));
+    manager.listen();
+    assert_eq!(fleet.merge(&mut tracks, &manager), None, "our own echo");
+
+    let report = RadioMessage {
+        class: Class::Cruiser,
+        ..message(MessageID::Contact, 3, 1, vec2(100.0, 0.0), vec2(60.0, 0.0), 30)
+    };
+    mock::with(|env| env.radios[0].inbox = Some(report.encode()));
+    manager.listen();
+    let id = fleet.merge(&mut tracks, &manager).unwrap();
+    let track = tracks.get(id).unwrap();
+    assert_eq!(track.class, Class::Cruiser);
+    assert!((track.position_at(1.0) - vec2(130.0, 0.0)).length() < 1e-6, "half a second old at 60 m/s");
+}
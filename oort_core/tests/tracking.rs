@@ -0,0 +1,150 @@
+/**************************************************************
+* Tracking tests
+* Association of measurements to tracks and the tentative,
+* confirmed, coasting and dropped lifecycle, and merging
+* tracks the fleet reports
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::kalman::MeasurementNoise;
+use oort_core::tracking::{TrackState, TrackTable};
+
+const NOISE: MeasurementNoise = MeasurementNoise { position: 5.0, velocity: 2.5 };
+
+fn time(tick: u32) -> f64 {
+    tick as f64 * TICK_LENGTH
+}
+
+#[test]
+fn scans_of_one_contact_keep_one_id() {
+    let mut table = TrackTable::create();
+    let velocity = vec2(200.0, 0.0);
+    let ids: Vec<_> = (0..30)
+        .map(|tick| table.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0) + velocity * time(tick), velocity, NOISE, time(tick)))
+        .collect();
+    assert!(ids.iter().all(|id| *id == ids[0]));
+    assert_eq!(table.tracks.len(), 1);
+}
+
+#[test]
+fn separate_contacts_get_separate_tracks() {
+    let mut table = TrackTable::create();
+    for tick in 0..10 {
+        // a sweeping radar sees one contact per tick, alternating
+        let position = if tick % 2 == 0 { vec2(1000.0, 0.0) } else { vec2(1000.0, 800.0) };
+        table.ingest_measurement(Class::Fighter, position, vec2(0.0, 0.0), NOISE, time(tick));
+    }
+    assert_eq!(table.tracks.len(), 2);
+    assert!(table.tracks.iter().all(|track| track.hits == 5));
+}
+
+#[test]
+fn class_mismatch_is_never_associated() {
+    let mut table = TrackTable::create();
+    let fighter = table.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), NOISE, 0.0);
+    let missile = table.ingest_measurement(Class::Missile, vec2(1001.0, 0.0), vec2(0.0, 0.0), NOISE, time(1));
+    assert_ne!(fighter, missile);
+}
+
+#[test]
+fn lifecycle_confirms_coasts_and_drops() {
+    let mut table = TrackTable::create().with_timeouts(1.0, 3.0);
+    let id = table.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), NOISE, 0.0);
+    table.update(0.0);
+    assert_eq!(table.get(id).unwrap().state, TrackState::Tentative);
+    for tick in 1..3 {
+        table.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), NOISE, time(tick));
+    }
+    table.update(time(2));
+    assert_eq!(table.get(id).unwrap().state, TrackState::Confirmed);
+
+    table.update(2.0);
+    let track = table.get(id).unwrap();
+    assert_eq!(track.state, TrackState::Coasting);
+    assert!((track.age(2.0) - 2.0).abs() < 1e-9);
+    assert!(table.nearest(vec2(0.0, 0.0), 2.0).is_some(), "coasting tracks are still targets");
+
+    table.update(3.5);
+    assert_eq!(table.tracks[0].state, TrackState::Dropped);
+    assert!(table.get(id).is_none());
+    table.update(3.6);
+    assert!(table.tracks.is_empty());
+}
+
+#[test]
+fn unconfirmed_tracks_drop_quietly() {
+    let mut table = TrackTable::create();
+    table.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), NOISE, 0.0);
+    table.update(2.0);
+    assert_eq!(table.tracks[0].state, TrackState::Dropped);
+    assert!(table.nearest(vec2(0.0, 0.0), 2.0).is_none());
+}
+
+#[test]
+fn coasting_track_reacquires_its_id() {
+    let mut table = TrackTable::create().with_confirmation(1);
+    let velocity = vec2(0.0, 100.0);
+    let id = table.ingest_measurement(Class::Frigate, vec2(2000.0, 0.0), velocity, NOISE, 0.0);
+    table.update(1.5);
+    assert_eq!(table.get(id).unwrap().state, TrackState::Coasting);
+    let again = table.ingest_measurement(Class::Frigate, vec2(2000.0, 150.0), velocity, NOISE, 1.5);
+    assert_eq!(again, id);
+    assert_eq!(table.get(id).unwrap().state, TrackState::Confirmed);
+}
+
+#[test]
+fn report_starts_a_confirmed_remote_track() {
+    let mut table = TrackTable::create();
+    let id = table.ingest_report(Class::Fighter, vec2(1000.0, 0.0), vec2(100.0, 0.0), 0.5, 2.0).unwrap();
+    let track = *table.get(id).unwrap();
+    assert_eq!(track.state, TrackState::Confirmed, "the sender already confirmed it");
+    assert_eq!(track.last_local, None);
+    assert_eq!(track.last_seen, 1.5);
+    assert!((track.position_at(2.0) - vec2(1050.0, 0.0)).length() < 1e-9, "moved on by the report's age");
+    assert_eq!(table.shareable(2.0).count(), 0, "relayed tracks are not shared again");
+}
+
+#[test]
+fn stale_reports_are_ignored() {
+    let mut table = TrackTable::create().with_timeouts(1.0, 3.0);
+    assert_eq!(table.ingest_report(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), 3.5, 10.0), None);
+    assert!(table.tracks.is_empty());
+    table.ingest_report(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), 2.0, 10.0).unwrap();
+    table.update(10.0);
+    assert!(table.firm().all(|track| track.state == TrackState::Coasting), "old news coasts straight away");
+}
+
+#[test]
+fn report_of_a_local_track_updates_it() {
+    let mut table = TrackTable::create().with_confirmation(1);
+    let id = table.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), NOISE, 0.0);
+    let merged = table.ingest_report(Class::Fighter, vec2(1030.0, 0.0), vec2(0.0, 0.0), 0.1, time(1));
+    assert_eq!(merged, Some(id));
+    assert_eq!(table.tracks.len(), 1);
+    assert_eq!(table.get(id).unwrap().last_local, Some(0.0), "a report is not our own radar");
+    assert_eq!(table.shareable(time(1)).map(|track| track.id).collect::<Vec<_>>(), vec![id]);
+}
+
+#[test]
+fn duplicate_reported_track_folds_into_the_local_one() {
+    let mut table = TrackTable::create();
+    let remote = table.ingest_report(Class::Cruiser, vec2(1100.0, 0.0), vec2(0.0, 0.0), 0.0, 0.0).unwrap();
+    for tick in 0..3 {
+        table.ingest_measurement(Class::Cruiser, vec2(1000.0, 0.0), vec2(0.0, 0.0), NOISE, time(tick));
+    }
+    table.update(time(3));
+    assert_eq!(table.firm().count(), 2, "the report is outside the new track's gate");
+    table.update(1.0);
+    let firm: Vec<_> = table.firm().collect();
+    assert_eq!(firm.len(), 1, "folded once the report has aged");
+    assert_ne!(firm[0].id, remote, "our own radar's track is kept");
+    assert_eq!(firm[0].hits, 4);
+}
+
+#[test]
+fn local_tracks_are_never_folded() {
+    let mut table = TrackTable::create().with_confirmation(1).with_gate(0.0, 1.0);
+    table.ingest_measurement(Class::Fighter, vec2(1000.0, 0.0), vec2(0.0, 0.0), NOISE, 0.0);
+    table.ingest_measurement(Class::Fighter, vec2(1010.0, 0.0), vec2(0.0, 0.0), NOISE, 0.0);
+    table.update(0.0);
+    assert_eq!(table.firm().count(), 2);
+}
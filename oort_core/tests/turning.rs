@@ -0,0 +1,148 @@
+/**************************************************************
+* Turning tests
+* The bang-bang turn flown tick by tick with the game's
+* integrator, against the continuous minimum turn time, and
+* the tracking turn held on a mark that keeps moving
+****************************************************************/
+use oort_api::mock::{self, Environment};
+use oort_api::prelude::*;
+use oort_core::turning::{
+    bang_bang_torque, mark_rate, min_turn_time, quickest_way_round, tracking_torque, BangBangTurn, HeadingController, PdTurn, FIGHTER_TURN, SEAHORSE_TURN,
+};
+
+const DT: f64 = 1.0 / 60.0;
+const FIGHTER: f64 = TAU; // rad/s^2
+const FRIGATE: f64 = PI / 4.0;
+
+// Ticks until the ship comes to rest on the mark and stays there
+fn settle_ticks(angle_to_mark: f64, spin: f64, max_acceleration: f64) -> usize {
+    let (mark, mut heading, mut spin) = (1.0, (1.0 - angle_to_mark).rem_euclid(TAU), spin);
+    let mut settled_at = None;
+    for tick in 0..1200 {
+        let torque = bang_bang_torque(angle_diff(heading, mark), spin, max_acceleration);
+        assert!(torque.abs() <= max_acceleration + 1e-9);
+        spin += torque * DT;
+        heading = (heading + spin * DT).rem_euclid(TAU);
+        let settled = angle_diff(heading, mark).abs() < 1e-3 && spin.abs() < 1e-2;
+        settled_at = match (settled, settled_at) {
+            (true, None) => Some(tick + 1),
+            (true, at) => at,
+            (false, _) => None,
+        };
+    }
+    settled_at.expect("never settled")
+}
+
+fn optimal_ticks(angle_to_mark: f64, spin: f64, max_acceleration: f64) -> usize {
+    let angle = quickest_way_round(angle_to_mark, spin, max_acceleration);
+    (min_turn_time(angle, spin, max_acceleration) / DT).ceil() as usize
+}
+
+#[test]
+fn minimum_turn_time_by_hand() {
+    // from rest: half the way accelerating, half braking
+    assert!((min_turn_time(PI, 0.0, FIGHTER) - 2.0 * (PI / FIGHTER).sqrt()).abs() < 1e-12);
+    assert_eq!(min_turn_time(-1.0, 0.0, FIGHTER), min_turn_time(1.0, 0.0, FIGHTER));
+    // already on the braking curve
+    let spin = (2.0 * FIGHTER).sqrt();
+    assert!((min_turn_time(1.0, spin, FIGHTER) - spin / FIGHTER).abs() < 1e-12);
+    // spinning the wrong way costs the time to stop first
+    assert!(min_turn_time(1.0, -2.0, FIGHTER) > min_turn_time(1.0, 0.0, FIGHTER) + 2.0 / FIGHTER);
+}
+
+#[test]
+fn fast_spin_carries_on_round_instead_of_reversing() {
+    assert_eq!(quickest_way_round(3.0, 0.0, FIGHTER), 3.0);
+    assert!((quickest_way_round(3.0, -8.0, FIGHTER) - (3.0 - TAU)).abs() < 1e-12);
+    assert!((quickest_way_round(-3.0, 8.0, FIGHTER) - (TAU - 3.0)).abs() < 1e-12);
+}
+
+#[test]
+fn settles_within_one_tick_of_optimal() {
+    let cases = [
+        (PI / 2.0, 0.0, FIGHTER),
+        (-2.5, 0.0, FIGHTER),
+        (0.05, 0.0, FIGHTER),
+        (0.3, -3.0, FIGHTER),       // reversal
+        (3.0, -8.0, FIGHTER),       // quicker the long way round
+        (0.01, 2.0, FIGHTER),       // overshoots and comes back
+        (-PI + 0.01, 0.5, FIGHTER), // wraps past PI
+        (1.0, 0.0, FRIGATE),
+        (-0.4, 0.6, FRIGATE),
+    ];
+    for (angle, spin, max_acceleration) in cases {
+        let (settled, optimal) = (settle_ticks(angle, spin, max_acceleration), optimal_ticks(angle, spin, max_acceleration));
+        assert!(settled <= optimal + 1, "{angle} at {spin} rad/s took {settled} ticks, optimal {optimal}");
+    }
+}
+
+#[test]
+fn holds_still_on_the_mark() {
+    assert_eq!(bang_bang_torque(0.0, 0.0, FIGHTER), 0.0);
+    assert_eq!(bang_bang_torque(PI, 0.0, FIGHTER), FIGHTER);
+    assert_eq!(bang_bang_torque(-PI / 2.0, 0.0, FIGHTER), -FIGHTER);
+}
+
+#[test]
+fn settles_within_one_tick_of_optimal_across_the_state_space() {
+    for angle_step in -8..=8 {
+        for spin_step in -4..=4 {
+            let (angle, spin) = (angle_step as f64 * 0.39, spin_step as f64 * 1.7);
+            let (settled, optimal) = (settle_ticks(angle, spin, FIGHTER), optimal_ticks(angle, spin, FIGHTER));
+            assert!(settled <= optimal + 1, "{angle} at {spin} rad/s took {settled} ticks, optimal {optimal}");
+        }
+    }
+}
+
+// Worst heading error over the last second of chasing a mark sweeping round at
+// mark_rate, with the rate fed forward or left out
+fn tracking_error(mark_rate: f64, feed_forward: bool) -> f64 {
+    let (mut mark, mut heading, mut spin) = (1.0, 0.0, 0.0);
+    let mut worst: f64 = 0.0;
+    for tick in 0..300 {
+        let angle_to_mark = angle_diff(heading, mark);
+        let torque = if feed_forward {
+            tracking_torque(angle_to_mark, spin, mark_rate, FIGHTER)
+        } else {
+            bang_bang_torque(angle_to_mark, spin, FIGHTER)
+        };
+        spin += torque * DT;
+        heading = (heading + spin * DT).rem_euclid(TAU);
+        mark = (mark + mark_rate * DT).rem_euclid(TAU);
+        if tick >= 240 {
+            worst = worst.max(angle_diff(heading, mark).abs());
+        }
+    }
+    worst
+}
+
+#[test]
+fn tracking_keeps_the_nose_on_a_crossing_mark() {
+    for mark_rate in [0.4, -1.5, 3.0] {
+        assert!(tracking_error(mark_rate, true) < 1e-9, "{mark_rate} rad/s");
+        // a fixed mark every tick falls behind and hunts
+        assert!(tracking_error(mark_rate, false) > 1e-3, "{mark_rate} rad/s");
+    }
+}
+
+#[test]
+fn mark_rate_of_a_crossing_lead_point() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.position = vec2(0.0, 0.0);
+    env.velocity = vec2(50.0, 0.0);
+    mock::install(env);
+    // 100 m/s across the line of sight 1km out turns it at 0.1 rad/s, closing adds nothing
+    assert!((mark_rate(vec2(1000.0, 0.0), vec2(50.0, 100.0)) - 0.1).abs() < 1e-12);
+    assert!((mark_rate(vec2(0.0, 1000.0), vec2(50.0, 0.0))).abs() < 1e-12);
+    assert_eq!(mark_rate(vec2(0.0, 0.0), vec2(0.0, 100.0)), 0.0);
+}
+
+#[test]
+fn every_controller_turns_with_the_mark_once_on_it() {
+    let controllers: [&dyn HeadingController; 4] = [&PdTurn::create(40.0, 6.0), &SEAHORSE_TURN, &BangBangTurn, &FIGHTER_TURN];
+    for controller in controllers {
+        assert_eq!(controller.torque_for(0.0, 0.0, 0.0, FIGHTER), 0.0);
+        assert_eq!(controller.torque_for(0.0, 0.7, 0.7, FIGHTER), 0.0);
+        assert!(controller.torque_for(0.0, 0.0, 0.7, FIGHTER) > 0.0);
+    }
+}
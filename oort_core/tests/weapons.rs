@@ -0,0 +1,50 @@
+/**************************************************************
+* Weapon tests
+* Reload timers, turret allocation by threat and salvo timing
+****************************************************************/
+use oort_api::mock::{self, Environment};
+use oort_api::prelude::*;
+use oort_core::targeting::UnitDescription;
+use oort_core::weapons::{allocate_turrets, Mount, Salvo, WeaponSlot};
+
+#[test]
+fn slots_reload_on_their_own_timers() {
+    mock::install(Environment::for_class(Class::Cruiser));
+    let mut turret = WeaponSlot::create(0, Mount::Turret, 24);
+    let mut launcher = WeaponSlot::create(1, Mount::Launcher(Class::Missile), 120);
+    assert!(turret.fire() && launcher.fire());
+    assert!(!turret.fire(), "still reloading");
+    for _ in 0..24 {
+        turret.update();
+        launcher.update();
+    }
+    assert!(turret.is_ready());
+    assert_eq!(launcher.ticks_till_reload, 96);
+    assert_eq!(mock::take_commands().fired, vec![0, 1]);
+}
+
+#[test]
+fn turrets_go_to_the_most_threatening_tracks() {
+    mock::install(Environment::for_class(Class::Cruiser));
+    let fighter = UnitDescription::create(Class::Fighter, vec2(1000.0, 100.0), vec2(0.0, 0.0), None);
+    let missile = UnitDescription::create(Class::Missile, vec2(-3000.0, 100.0), vec2(500.0, 0.0), None);
+    let allocation = allocate_turrets(&[0, 4], &[fighter, missile]);
+    assert_eq!(allocation, vec![(0, missile), (4, fighter)]);
+    assert_eq!(allocate_turrets(&[0, 4], &[fighter]), vec![(0, fighter), (4, fighter)]);
+    assert!(allocate_turrets(&[0], &[]).is_empty());
+}
+
+#[test]
+fn salvo_lands_together() {
+    let mut salvo = Salvo::create(7, &[(1, 4.0), (3, 10.0)], 100.0).unwrap();
+    assert_eq!(salvo.arrival, 110.0);
+    assert!(salvo.is_due(3, 10.0, 100.0), "the slowest goes first");
+    assert!(!salvo.is_due(1, 4.0, 100.0));
+    salvo.launched(3);
+    assert!(!salvo.is_pending(3) && salvo.is_pending(1));
+    assert!(!salvo.is_due(3, 10.0, 106.0), "already away");
+    assert!(salvo.is_due(1, 4.0, 106.0));
+    salvo.launched(1);
+    assert!(salvo.is_complete());
+    assert_eq!(Salvo::create(7, &[], 100.0), None);
+}
@@ -0,0 +1,35 @@
+/*******************************************************************
+* ** Controllers **
+* Anything that can be ticked like a game Ship. Tutorial Ships
+* are wrapped through factory(Ship::new, Ship::tick), scripted
+* opponents implement the trait directly
+********************************************************************/
+use std::rc::Rc;
+
+pub trait Controller {
+    fn tick(&mut self);
+}
+
+// Builds the controller for a newly spawned unit, including missiles launched by it
+pub type Factory = Rc<dyn Fn() -> Box<dyn Controller>>;
+
+// Adapts any type following the game's `Ship::new()`/`Ship::tick()` convention
+struct Ticker<S> {
+    ship: S,
+    tick: fn(&mut S),
+}
+
+impl<S> Controller for Ticker<S> {
+    fn tick(&mut self) {
+        (self.tick)(&mut self.ship)
+    }
+}
+
+pub fn factory<S: 'static>(new: fn() -> S, tick: fn(&mut S)) -> Factory {
+    Rc::new(move || Box::new(Ticker { ship: new(), tick }))
+}
+
+// Factory for a scripted controller that is cloned for every spawn
+pub fn scripted<C: Controller + Clone + 'static>(controller: C) -> Factory {
+    Rc::new(move || Box::new(controller.clone()))
+}
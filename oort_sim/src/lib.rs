@@ -0,0 +1,21 @@
+/**************************************************************
+* oort_sim
+* Author: Christopher Dean
+* Headless, deterministic stand-in for the oort.rs world.
+* Steps any Ship written against oort_api at 60 ticks per
+* second: class acceleration limits, bullets, missiles with
+* fuel, radar cones and radio channels. Ship code runs
+* against the oort_api mock, one environment per unit.
+* Numbers are close to the game, not identical to it.
+****************************************************************/
+pub mod controller;
+pub mod physics;
+pub mod radar;
+pub mod radio;
+pub mod random;
+pub mod simulation;
+pub mod unit;
+
+pub use controller::{factory, scripted, Controller, Factory};
+pub use simulation::Simulation;
+pub use unit::{Team, Unit, UnitSpec};
@@ -0,0 +1,60 @@
+/*******************************************************************
+* ** Physics **
+* Clamps requested thrust to the class limits and integrates
+* bodies with a fixed 1/60s step
+********************************************************************/
+use oort_api::mock::Limits;
+use oort_api::prelude::*;
+
+// Requested world frame acceleration clamped forward/backward/lateral relative to the heading
+pub fn clamp_acceleration(acceleration: Vec2, heading: f64, limits: &Limits) -> Vec2 {
+    let local = acceleration.rotate(-heading);
+    let forward = local.x.clamp(-limits.max_backward_acceleration, limits.max_forward_acceleration);
+    let lateral = local.y.clamp(-limits.max_lateral_acceleration, limits.max_lateral_acceleration);
+    vec2(forward, lateral).rotate(heading)
+}
+
+pub fn clamp_torque(angular_acceleration: f64, limits: &Limits) -> f64 {
+    angular_acceleration.clamp(-limits.max_angular_acceleration, limits.max_angular_acceleration)
+}
+
+// turn() asks for an angular velocity, reached as quickly as the torque limit allows
+pub fn turn_torque(target_angular_velocity: f64, angular_velocity: f64, limits: &Limits) -> f64 {
+    clamp_torque((target_angular_velocity - angular_velocity) / TICK_LENGTH, limits)
+}
+
+// Semi-implicit Euler, returns the new (position, velocity)
+pub fn integrate_linear(position: Vec2, velocity: Vec2, acceleration: Vec2) -> (Vec2, Vec2) {
+    let velocity = velocity + acceleration * TICK_LENGTH;
+    (position + velocity * TICK_LENGTH, velocity)
+}
+
+// Returns the new (heading, angular_velocity), heading kept in [0, TAU)
+pub fn integrate_angular(heading: f64, angular_velocity: f64, angular_acceleration: f64) -> (f64, f64) {
+    let angular_velocity = angular_velocity + angular_acceleration * TICK_LENGTH;
+    ((heading + angular_velocity * TICK_LENGTH).rem_euclid(TAU), angular_velocity)
+}
+
+// Closest distance between `point` and the segment from `start` to `end`
+pub fn segment_distance(start: Vec2, end: Vec2, point: Vec2) -> f64 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared == 0.0 {
+        return start.distance(point);
+    }
+    let t = ((point - start).dot(segment) / length_squared).clamp(0.0, 1.0);
+    (start + segment * t).distance(point)
+}
+
+// Keeps bodies inside the square world, killing the velocity into the wall
+pub fn contain(position: &mut Vec2, velocity: &mut Vec2, world_size: f64) {
+    let half = world_size / 2.0;
+    if position.x.abs() > half {
+        position.x = position.x.clamp(-half, half);
+        velocity.x = 0.0;
+    }
+    if position.y.abs() > half {
+        position.y = position.y.clamp(-half, half);
+        velocity.y = 0.0;
+    }
+}
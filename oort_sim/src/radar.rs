@@ -0,0 +1,69 @@
+/*******************************************************************
+* ** Radar **
+* A cone from radar_heading +/- width/2 between the min and max
+* distance. Returns scale with cross section, narrow beams and
+* range, noise on the reported position and velocity grows as
+* the signal fades
+********************************************************************/
+use crate::random::Random;
+use crate::unit::radar_cross_section;
+use oort_api::mock::Radar;
+use oort_api::prelude::*;
+
+pub const REFERENCE_RANGE: f64 = 20000.0; // a fighter fades into the noise here with the default beam
+pub const DEFAULT_WIDTH: f64 = TAU / 60.0;
+pub const NOISE_FLOOR: f64 = -100.0; // dB, rssi = snr + NOISE_FLOOR
+
+// Signal to noise in dB, <= 0 is not detected
+pub fn snr(class: Class, distance: f64, width: f64) -> f64 {
+    let gain = DEFAULT_WIDTH / width.max(1e-6);
+    let power = radar_cross_section(class) * gain * REFERENCE_RANGE * REFERENCE_RANGE / distance.max(1.0).powi(2);
+    10.0 * power.log10()
+}
+
+pub fn in_beam(radar: &Radar, origin: Vec2, position: Vec2) -> bool {
+    let offset = position - origin;
+    let distance = offset.length();
+    if distance < radar.min_distance || distance > radar.max_distance {
+        return false;
+    }
+    angle_diff(radar.heading, offset.angle()).abs() <= radar.width / 2.0
+}
+
+/*****************************************************
+* Contact
+* What the radar can see of another unit
+*******************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contact {
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+// Strongest return in the beam, with noise scaled by signal strength
+pub fn scan(radar: &Radar, origin: Vec2, contacts: impl Iterator<Item = Contact>, noise: Option<&mut Random>) -> Option<ScanResult> {
+    let (contact, snr) = contacts
+        .filter(|contact| in_beam(radar, origin, contact.position))
+        .map(|contact| (contact, snr(contact.class, origin.distance(contact.position), radar.width)))
+        .filter(|(_, snr)| *snr > 0.0)
+        .max_by(|a, b| a.1.total_cmp(&b.1))?;
+    let mut result = ScanResult {
+        class: contact.class,
+        position: contact.position,
+        velocity: contact.velocity,
+        rssi: snr + NOISE_FLOOR,
+        snr,
+    };
+    if let Some(random) = noise {
+        let sigma = position_error(origin.distance(contact.position), radar.width, snr);
+        result.position += vec2(random.gaussian(), random.gaussian()) * sigma;
+        result.velocity += vec2(random.gaussian(), random.gaussian()) * (sigma / 2.0);
+    }
+    Some(result)
+}
+
+// One sigma of reported position error, in meters
+pub fn position_error(distance: f64, width: f64, snr: f64) -> f64 {
+    distance * width * 10f64.powf(-snr / 20.0)
+}
@@ -0,0 +1,28 @@
+/*******************************************************************
+* ** Radio **
+* Messages sent during a tick arrive on the next one, on every
+* same team radio tuned to the channel. A radio holds one
+* message, the last one sent wins
+********************************************************************/
+use crate::unit::Team;
+use oort_api::mock::Radio;
+use oort_api::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Broadcast {
+    pub team: Team,
+    pub sender: u32,
+    pub channel: usize,
+    pub message: Message,
+}
+
+// Fills the radios of `receiver` from last tick's broadcasts, dropping anything unread
+pub fn deliver(broadcasts: &[Broadcast], team: Team, receiver: u32, radios: &mut [Radio]) {
+    for radio in radios.iter_mut() {
+        radio.inbox = broadcasts
+            .iter()
+            .rev()
+            .find(|broadcast| broadcast.team == team && broadcast.sender != receiver && broadcast.channel == radio.channel)
+            .map(|broadcast| broadcast.message);
+    }
+}
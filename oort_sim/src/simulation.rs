@@ -0,0 +1,420 @@
+/*******************************************************************
+* ** Simulation **
+* Owns every unit and projectile and steps them together. Each
+* tick: scan and deliver radio, run every controller against
+* its own mock environment, apply the commands, integrate,
+* resolve bullets and warheads, then launch new missiles
+********************************************************************/
+use crate::physics::{clamp_acceleration, clamp_torque, contain, integrate_angular, integrate_linear, segment_distance, turn_torque};
+use crate::radar::{scan, Contact};
+use crate::radio::{deliver, Broadcast};
+use crate::random::Random;
+use crate::unit::{hit_radius, warhead, weapons_for_class, Bullet, Team, Unit, UnitSpec, WeaponKind};
+use oort_api::mock::{self, Commands, Environment};
+use oort_api::prelude::*;
+use std::collections::BTreeMap;
+
+const LAUNCH_SPEED: f64 = 100.0; // m/s added to the launcher's velocity
+
+// When and what was destroyed, for scoring runs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Destruction {
+    pub id: u32,
+    pub team: Team,
+    pub class: Class,
+    pub tick: u32,
+}
+
+// Rounds and warheads a team put out, and how many of them connected
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Tally {
+    pub shots: u32,
+    pub hits: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Detonation {
+    team: Team,
+    position: Vec2,
+    radius: f64,
+    damage: f64,
+}
+
+pub struct Simulation {
+    pub world_size: f64,
+    pub radar_noise: bool,
+    seed: u64,
+    tick: u32,
+    next_id: u32,
+    units: Vec<Unit>,
+    bullets: Vec<Bullet>,
+    broadcasts: Vec<Broadcast>,
+    destroyed: Vec<Destruction>,
+    tallies: BTreeMap<Team, Tally>,
+    random: Random,
+}
+
+impl Simulation {
+    pub fn create(seed: u64) -> Simulation {
+        Simulation {
+            world_size: 40000.0,
+            radar_noise: false,
+            seed,
+            tick: 0,
+            next_id: 0,
+            units: Vec::new(),
+            bullets: Vec::new(),
+            broadcasts: Vec::new(),
+            destroyed: Vec::new(),
+            tallies: BTreeMap::new(),
+            random: Random::create(seed),
+        }
+    }
+
+    pub fn with_world_size(mut self, world_size: f64) -> Simulation {
+        self.world_size = world_size;
+        self
+    }
+
+    // Adds snr scaled error to every scan result
+    pub fn with_radar_noise(mut self) -> Simulation {
+        self.radar_noise = true;
+        self
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    pub fn time(&self) -> f64 {
+        self.tick as f64 * TICK_LENGTH
+    }
+
+    pub fn unit(&self, id: u32) -> Option<&Unit> {
+        self.units.iter().find(|unit| unit.id == id)
+    }
+
+    pub fn units(&self) -> impl Iterator<Item = &Unit> {
+        self.units.iter()
+    }
+
+    pub fn bullets(&self) -> &[Bullet] {
+        &self.bullets
+    }
+
+    pub fn destroyed(&self) -> &[Destruction] {
+        &self.destroyed
+    }
+
+    pub fn tally(&self, team: Team) -> Tally {
+        self.tallies.get(&team).copied().unwrap_or_default()
+    }
+
+    // Ships still fighting for `team`, missiles and torpedoes not included
+    pub fn ships_alive(&self, team: Team) -> usize {
+        self.units.iter().filter(|unit| unit.team == team && warhead(unit.class).is_none()).count()
+    }
+
+    // What target()/target_velocity() report for `id`
+    pub fn set_target(&mut self, id: u32, target: u32) {
+        if let Some(unit) = self.units.iter_mut().find(|unit| unit.id == id) {
+            unit.target = Some(target);
+        }
+    }
+
+    pub fn spawn(&mut self, spec: UnitSpec) -> u32 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let mut environment = Environment::for_class(spec.class).with_seed(self.seed.wrapping_mul(0x9e37_79b9).wrapping_add(id as u64));
+        environment.id = id;
+        environment.world_size = self.world_size;
+        if let Some(limits) = spec.limits {
+            environment.limits = limits;
+        }
+        let weapons = weapons_for_class(spec.class);
+        let mut unit = Unit {
+            id,
+            team: spec.team,
+            class: spec.class,
+            position: spec.position,
+            velocity: spec.velocity,
+            heading: spec.heading.rem_euclid(TAU),
+            angular_velocity: 0.0,
+            health: spec.health.unwrap_or(environment.health),
+            fuel: environment.fuel,
+            target: None,
+            reload: vec![0; weapons.len()],
+            weapons,
+            exploded: false,
+            environment,
+            controller: None,
+            factory: spec.controller.clone(),
+        };
+        if let Some(factory) = spec.controller {
+            self.sync_environment(&mut unit);
+            let previous = mock::install(unit.environment.clone());
+            unit.controller = Some(factory());
+            unit.environment = mock::install(previous);
+            unit.environment.commands = Commands::default();
+        }
+        self.units.push(unit);
+        id
+    }
+
+    // Steps until `done` holds or `max_ticks` pass, returns whether `done` was reached
+    pub fn run_until(&mut self, max_ticks: u32, mut done: impl FnMut(&Simulation) -> bool) -> bool {
+        for _ in 0..max_ticks {
+            if done(self) {
+                return true;
+            }
+            self.step();
+        }
+        done(self)
+    }
+
+    pub fn step(&mut self) {
+        let contacts: Vec<(u32, Team, Contact)> = self
+            .units
+            .iter()
+            .map(|unit| {
+                let contact = Contact {
+                    class: unit.class,
+                    position: unit.position,
+                    velocity: unit.velocity,
+                };
+                (unit.id, unit.team, contact)
+            })
+            .collect();
+
+        // run every controller against its own environment
+        let mut orders = Vec::with_capacity(self.units.len());
+        for index in 0..self.units.len() {
+            let mut unit = std::mem::replace(&mut self.units[index], placeholder());
+            self.sync_environment(&mut unit);
+            if let Some(target) = unit.target.and_then(|target| contacts.iter().find(|(id, _, _)| *id == target)) {
+                unit.environment.target = target.2.position;
+                unit.environment.target_velocity = target.2.velocity;
+            }
+            let enemies = contacts.iter().filter(|(_, team, _)| *team != unit.team).map(|(_, _, contact)| *contact);
+            let noise = if self.radar_noise { Some(&mut self.random) } else { None };
+            unit.environment.scan_result = scan(&unit.environment.radar, unit.position, enemies, noise);
+            deliver(&self.broadcasts, unit.team, unit.id, &mut unit.environment.radios);
+            if let Some(controller) = unit.controller.as_mut() {
+                let previous = mock::install(unit.environment.clone());
+                controller.tick();
+                unit.environment = mock::install(previous);
+            }
+            orders.push(std::mem::take(&mut unit.environment.commands));
+            self.units[index] = unit;
+        }
+
+        self.broadcasts = self
+            .units
+            .iter()
+            .zip(&orders)
+            .flat_map(|(unit, commands)| {
+                commands.sent.iter().map(|sent| Broadcast {
+                    team: unit.team,
+                    sender: unit.id,
+                    channel: sent.channel,
+                    message: sent.message,
+                })
+            })
+            .collect();
+
+        let mut launches = Vec::new();
+        let mut detonations = Vec::new();
+        for (unit, commands) in self.units.iter_mut().zip(&orders) {
+            let (bullet_count, launch_count) = (self.bullets.len(), launches.len());
+            launches.extend(apply_commands(unit, commands, &mut self.bullets));
+            let shots = (self.bullets.len() - bullet_count) + (launches.len() - launch_count);
+            self.tallies.entry(unit.team).or_default().shots += shots as u32;
+            contain(&mut unit.position, &mut unit.velocity, self.world_size);
+            if commands.exploded {
+                unit.exploded = true;
+            }
+        }
+
+        // warheads that reached an enemy go off on contact
+        for index in 0..self.units.len() {
+            let unit = &self.units[index];
+            if warhead(unit.class).is_none() || unit.exploded {
+                continue;
+            }
+            let touching = self
+                .units
+                .iter()
+                .any(|other| other.team != unit.team && other.position.distance(unit.position) <= hit_radius(other.class) + hit_radius(unit.class));
+            if touching {
+                self.units[index].exploded = true;
+            }
+        }
+        for unit in self.units.iter().filter(|unit| unit.exploded) {
+            if let Some((radius, damage)) = warhead(unit.class) {
+                detonations.push(Detonation {
+                    team: unit.team,
+                    position: unit.position,
+                    radius,
+                    damage,
+                });
+            }
+        }
+        for detonation in detonations {
+            let mut connected = false;
+            for unit in self.units.iter_mut().filter(|unit| unit.team != detonation.team) {
+                let distance = unit.position.distance(detonation.position);
+                if distance <= detonation.radius {
+                    unit.health -= detonation.damage * (1.0 - 0.5 * distance / detonation.radius);
+                    connected = true;
+                }
+            }
+            if connected {
+                self.tallies.entry(detonation.team).or_default().hits += 1;
+            }
+        }
+
+        self.update_bullets();
+
+        let tick = self.tick;
+        let destroyed = &mut self.destroyed;
+        self.units.retain(|unit| {
+            if !unit.alive() {
+                destroyed.push(Destruction {
+                    id: unit.id,
+                    team: unit.team,
+                    class: unit.class,
+                    tick,
+                });
+            }
+            unit.alive()
+        });
+
+        for (spec, target) in launches {
+            let id = self.spawn(spec);
+            if let Some(target) = target {
+                self.set_target(id, target);
+            }
+        }
+        self.tick += 1;
+    }
+
+    fn sync_environment(&self, unit: &mut Unit) {
+        let environment = &mut unit.environment;
+        environment.tick = self.tick;
+        environment.position = unit.position;
+        environment.velocity = unit.velocity;
+        environment.heading = unit.heading;
+        environment.angular_velocity = unit.angular_velocity;
+        environment.health = unit.health;
+        environment.fuel = unit.fuel;
+        environment.world_size = self.world_size;
+        environment.reload_ticks = unit.reload.clone();
+        environment.reload_ticks.resize(4, 0);
+    }
+
+    fn update_bullets(&mut self) {
+        let units = &mut self.units;
+        let tallies = &mut self.tallies;
+        self.bullets.retain_mut(|bullet| {
+            let start = bullet.position;
+            let end = start + bullet.velocity * TICK_LENGTH;
+            let hit = units
+                .iter_mut()
+                .filter(|unit| unit.team != bullet.team && unit.alive())
+                .find(|unit| segment_distance(start, end, unit.position) <= hit_radius(unit.class));
+            if let Some(unit) = hit {
+                unit.health -= bullet.damage;
+                tallies.entry(bullet.team).or_default().hits += 1;
+                return false;
+            }
+            bullet.position = end;
+            bullet.ticks_left = bullet.ticks_left.saturating_sub(1);
+            bullet.ticks_left > 0
+        });
+    }
+}
+
+// Applies thrust, torque and weapons for one unit, returning the missiles it launched
+fn apply_commands(unit: &mut Unit, commands: &Commands, bullets: &mut Vec<Bullet>) -> Vec<(UnitSpec, Option<u32>)> {
+    let limits = unit.environment.limits;
+    let mut acceleration = commands
+        .acceleration
+        .map_or(vec2(0.0, 0.0), |acceleration| clamp_acceleration(acceleration, unit.heading, &limits));
+    if unit.fuel.is_finite() {
+        let cost = acceleration.length() * TICK_LENGTH;
+        if cost > unit.fuel {
+            acceleration *= unit.fuel / cost;
+        }
+        unit.fuel = (unit.fuel - cost).max(0.0);
+    }
+    let angular_acceleration = match (commands.torque, commands.turn) {
+        (Some(torque), _) => clamp_torque(torque, &limits),
+        (None, Some(turn)) => turn_torque(turn, unit.angular_velocity, &limits),
+        (None, None) => 0.0,
+    };
+    (unit.position, unit.velocity) = integrate_linear(unit.position, unit.velocity, acceleration);
+    (unit.heading, unit.angular_velocity) = integrate_angular(unit.heading, unit.angular_velocity, angular_acceleration);
+
+    for reload in unit.reload.iter_mut() {
+        *reload = reload.saturating_sub(1);
+    }
+    let mut launches = Vec::new();
+    let mut fired = commands.fired.clone();
+    fired.dedup();
+    for index in fired {
+        let Some(weapon) = unit.weapons.get(index).copied() else {
+            continue;
+        };
+        if unit.reload[index] > 0 {
+            continue;
+        }
+        unit.reload[index] = weapon.reload_ticks;
+        let aim = commands.aimed.iter().rev().find(|(gun, _)| *gun == index).map(|(_, heading)| *heading);
+        let heading = if weapon.turret { aim.unwrap_or(unit.heading) } else { unit.heading };
+        let direction = vec2(1.0, 0.0).rotate(heading);
+        let muzzle = unit.position + direction * (hit_radius(unit.class) + 1.0);
+        match weapon.kind {
+            WeaponKind::Gun { speed, lifetime_ticks, damage } => bullets.push(Bullet {
+                team: unit.team,
+                position: muzzle,
+                velocity: unit.velocity + direction * speed,
+                damage,
+                ticks_left: lifetime_ticks,
+            }),
+            WeaponKind::Launcher(class) => {
+                let mut spec = UnitSpec::create(unit.team, class, muzzle)
+                    .with_velocity(unit.velocity + direction * LAUNCH_SPEED)
+                    .with_heading(heading);
+                spec.controller = unit.factory.clone();
+                launches.push((spec, unit.target));
+            }
+        }
+    }
+    launches
+}
+
+// Stands in for a unit while it is being ticked
+fn placeholder() -> Unit {
+    Unit {
+        id: u32::MAX,
+        team: Team::MAX,
+        class: Class::Unknown,
+        position: vec2(0.0, 0.0),
+        velocity: vec2(0.0, 0.0),
+        heading: 0.0,
+        angular_velocity: 0.0,
+        health: 0.0,
+        fuel: 0.0,
+        target: None,
+        weapons: Vec::new(),
+        reload: Vec::new(),
+        exploded: false,
+        environment: Environment::default(),
+        controller: None,
+        factory: None,
+    }
+}
@@ -0,0 +1,188 @@
+/*******************************************************************
+* ** Units **
+* Ships, missiles and scripted targets share one body type. The
+* per class tables below stand in for the game's ship designs
+********************************************************************/
+use crate::controller::{Controller, Factory};
+use oort_api::mock::{Environment, Limits};
+use oort_api::prelude::*;
+use oort_core::constants::{BULLET_SPEED, MISSILE_RELOAD_TIME, TICKS_PER_FIRE};
+
+pub type Team = u32;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WeaponKind {
+    Gun { speed: f64, lifetime_ticks: u32, damage: f64 },
+    Launcher(Class),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Weapon {
+    pub kind: WeaponKind,
+    pub reload_ticks: u32,
+    pub turret: bool, // aimed with aim(), otherwise fires along the ship heading
+}
+
+const fn gun(speed: f64, lifetime_ticks: u32, damage: f64, reload_ticks: u32, turret: bool) -> Weapon {
+    Weapon {
+        kind: WeaponKind::Gun { speed, lifetime_ticks, damage },
+        reload_ticks,
+        turret,
+    }
+}
+
+const fn launcher(class: Class, reload_ticks: u32) -> Weapon {
+    Weapon {
+        kind: WeaponKind::Launcher(class),
+        reload_ticks,
+        turret: false,
+    }
+}
+
+pub fn weapons_for_class(class: Class) -> Vec<Weapon> {
+    match class {
+        Class::Fighter => vec![gun(BULLET_SPEED, 300, 20.0, TICKS_PER_FIRE, false), launcher(Class::Missile, 300)],
+        Class::Frigate => vec![
+            gun(4000.0, 120, 1000.0, 120, false),
+            gun(BULLET_SPEED, 240, 20.0, 6, true),
+            gun(BULLET_SPEED, 240, 20.0, 6, true),
+            launcher(Class::Missile, MISSILE_RELOAD_TIME),
+        ],
+        Class::Cruiser => vec![
+            gun(2000.0, 120, 60.0, 24, true),
+            launcher(Class::Missile, MISSILE_RELOAD_TIME),
+            launcher(Class::Missile, MISSILE_RELOAD_TIME),
+            launcher(Class::Torpedo, 480),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+pub fn hit_radius(class: Class) -> f64 {
+    match class {
+        Class::Frigate => 40.0,
+        Class::Cruiser => 80.0,
+        Class::Asteroid => 50.0,
+        Class::Missile => 3.0,
+        Class::Torpedo => 5.0,
+        _ => 10.0,
+    }
+}
+
+// Relative radar return, a fighter is 1.0
+pub fn radar_cross_section(class: Class) -> f64 {
+    match class {
+        Class::Frigate => 4.0,
+        Class::Cruiser => 10.0,
+        Class::Asteroid => 8.0,
+        Class::Missile => 0.1,
+        Class::Torpedo => 0.2,
+        _ => 1.0,
+    }
+}
+
+// Blast radius and damage at the center when a missile or torpedo goes off
+pub fn warhead(class: Class) -> Option<(f64, f64)> {
+    match class {
+        Class::Missile => Some((150.0, 200.0)),
+        Class::Torpedo => Some((300.0, 2000.0)),
+        _ => None,
+    }
+}
+
+/*****************************************************
+* UnitSpec
+* Describes a unit to spawn. Units without a
+* controller simply drift
+*******************************************************/
+#[derive(Clone)]
+pub struct UnitSpec {
+    pub team: Team,
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub heading: f64,
+    pub health: Option<f64>,
+    pub limits: Option<Limits>,
+    pub controller: Option<Factory>,
+}
+
+impl UnitSpec {
+    pub fn create(team: Team, class: Class, position: Vec2) -> UnitSpec {
+        UnitSpec {
+            team,
+            class,
+            position,
+            velocity: vec2(0.0, 0.0),
+            heading: 0.0,
+            health: None,
+            limits: None,
+            controller: None,
+        }
+    }
+
+    pub fn with_velocity(mut self, velocity: Vec2) -> UnitSpec {
+        self.velocity = velocity;
+        self
+    }
+
+    pub fn with_heading(mut self, heading: f64) -> UnitSpec {
+        self.heading = heading;
+        self
+    }
+
+    pub fn with_health(mut self, health: f64) -> UnitSpec {
+        self.health = Some(health);
+        self
+    }
+
+    // Overrides the class acceleration limits, e.g. a ship that may only turn
+    pub fn with_limits(mut self, limits: Limits) -> UnitSpec {
+        self.limits = Some(limits);
+        self
+    }
+
+    pub fn with_controller(mut self, controller: Factory) -> UnitSpec {
+        self.controller = Some(controller);
+        self
+    }
+}
+
+pub struct Unit {
+    pub id: u32,
+    pub team: Team,
+    pub class: Class,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub heading: f64,
+    pub angular_velocity: f64,
+    pub health: f64,
+    pub fuel: f64,
+    pub target: Option<u32>, // what target()/target_velocity() report
+    pub weapons: Vec<Weapon>,
+    pub reload: Vec<u32>,
+    pub exploded: bool,
+    pub(crate) environment: Environment,
+    pub(crate) controller: Option<Box<dyn Controller>>,
+    pub(crate) factory: Option<Factory>,
+}
+
+impl Unit {
+    pub fn alive(&self) -> bool {
+        self.health > 0.0 && !self.exploded
+    }
+}
+
+/*****************************************************
+* Bullet
+* Point projectile, checked against hit radii along
+* the segment it covers each tick
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bullet {
+    pub team: Team,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub damage: f64,
+    pub ticks_left: u32,
+}
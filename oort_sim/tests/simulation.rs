@@ -0,0 +1,155 @@
+/**************************************************************
+* Simulation tests
+* Scripted controllers drive single units through the limits,
+* weapons, radar and radio of the simulator
+****************************************************************/
+use oort_api::prelude::*;
+use oort_sim::{Controller, Factory, Simulation, UnitSpec};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Script(Box<dyn FnMut()>);
+
+impl Controller for Script {
+    fn tick(&mut self) {
+        (self.0)()
+    }
+}
+
+fn script(tick: impl Fn() + Clone + 'static) -> Factory {
+    Rc::new(move || Box::new(Script(Box::new(tick.clone()))))
+}
+
+fn run_seconds(sim: &mut Simulation, seconds: f64) {
+    for _ in 0..(seconds * 60.0).round() as u32 {
+        sim.step();
+    }
+}
+
+#[test]
+fn acceleration_is_limited_relative_to_heading() {
+    let mut sim = Simulation::create(1);
+    let forward = sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, 0.0)).with_controller(script(|| accelerate(vec2(1000.0, 0.0)))));
+    let lateral = sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, 500.0)).with_controller(script(|| accelerate(vec2(0.0, 1000.0)))));
+    let backward = sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, -500.0)).with_controller(script(|| accelerate(vec2(-1000.0, 0.0)))));
+    run_seconds(&mut sim, 1.0);
+
+    assert!((sim.unit(forward).unwrap().velocity.x - 60.0).abs() < 1e-9);
+    assert!((sim.unit(lateral).unwrap().velocity.y - 30.0).abs() < 1e-9);
+    assert!((sim.unit(backward).unwrap().velocity.x + 30.0).abs() < 1e-9);
+}
+
+#[test]
+fn torque_is_limited_by_class() {
+    let mut sim = Simulation::create(1);
+    let id = sim.spawn(UnitSpec::create(0, Class::Frigate, vec2(0.0, 0.0)).with_controller(script(|| torque(100.0))));
+    run_seconds(&mut sim, 1.0);
+
+    assert!((sim.unit(id).unwrap().angular_velocity - PI / 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn missile_runs_out_of_fuel() {
+    let mut sim = Simulation::create(1);
+    let id = sim.spawn(UnitSpec::create(0, Class::Missile, vec2(0.0, 0.0)).with_controller(script(|| accelerate(vec2(300.0, 0.0)))));
+    run_seconds(&mut sim, 10.0);
+
+    let missile = sim.unit(id).unwrap();
+    assert_eq!(missile.fuel, 0.0);
+    assert!((missile.velocity.x - 2000.0).abs() < 1e-6);
+}
+
+#[test]
+fn bullets_destroy_target() {
+    let mut sim = Simulation::create(1);
+    sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, 0.0)).with_controller(script(|| fire(0))));
+    let target = sim.spawn(UnitSpec::create(1, Class::Fighter, vec2(500.0, 0.0)));
+    let destroyed = sim.run_until(600, |sim| sim.unit(target).is_none());
+
+    assert!(destroyed);
+    assert_eq!(sim.destroyed()[0].id, target);
+    assert!(sim.time() < 2.0);
+    let tally = sim.tally(0);
+    assert_eq!(tally.hits, 5, "20 damage a round against 100 health");
+    assert!(tally.shots >= tally.hits);
+    assert_eq!(sim.tally(1).shots, 0);
+}
+
+#[test]
+fn launched_missile_runs_the_launchers_controller() {
+    let mut sim = Simulation::create(1);
+    let tick = || {
+        if class() == Class::Missile {
+            accelerate(vec2(300.0, 0.0).rotate(heading()));
+        } else {
+            fire(1);
+        }
+    };
+    sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, 0.0)).with_controller(script(tick)));
+    let target = sim.spawn(UnitSpec::create(1, Class::Fighter, vec2(2000.0, 0.0)));
+    let destroyed = sim.run_until(600, |sim| sim.unit(target).is_none());
+
+    assert!(destroyed);
+}
+
+#[test]
+fn radar_reports_contacts_inside_the_beam() {
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let record = seen.clone();
+    let mut sim = Simulation::create(1);
+    sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, 0.0)).with_controller(script(move || {
+        record.borrow_mut().push(scan());
+        set_radar_heading(PI / 2.0);
+    })));
+    sim.spawn(UnitSpec::create(1, Class::Frigate, vec2(0.0, 5000.0)).with_velocity(vec2(10.0, 0.0)));
+    sim.step();
+    sim.step();
+
+    let seen = seen.borrow();
+    assert!(seen[0].is_none(), "beam starts pointed along +x");
+    let contact = seen[1].unwrap();
+    assert_eq!(contact.class, Class::Frigate);
+    assert_eq!(contact.velocity, vec2(10.0, 0.0));
+    assert!(contact.snr > 0.0);
+}
+
+#[test]
+fn radio_delivers_on_the_next_tick() {
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let record = received.clone();
+    let mut sim = Simulation::create(1);
+    sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, 0.0)).with_controller(script(|| {
+        set_radio_channel(2);
+        send([1.0, 2.0, 3.0, 4.0]);
+    })));
+    sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(100.0, 0.0)).with_controller(script(move || {
+        set_radio_channel(2);
+        record.borrow_mut().push(receive());
+    })));
+    sim.spawn(UnitSpec::create(1, Class::Fighter, vec2(200.0, 0.0)).with_controller(script(|| {
+        set_radio_channel(2);
+        assert_eq!(receive(), None, "other teams never hear us");
+    })));
+    sim.step();
+    sim.step();
+
+    assert_eq!(*received.borrow(), vec![None, Some([1.0, 2.0, 3.0, 4.0])]);
+}
+
+#[test]
+fn same_seed_replays_the_same_run() {
+    let run = |seed| {
+        let mut sim = Simulation::create(seed).with_radar_noise();
+        let id = sim.spawn(UnitSpec::create(0, Class::Fighter, vec2(0.0, 0.0)).with_controller(script(|| {
+            set_radar_heading(0.0);
+            if let Some(contact) = scan() {
+                accelerate(contact.position - position());
+            }
+        })));
+        sim.spawn(UnitSpec::create(1, Class::Fighter, vec2(5000.0, 0.0)));
+        run_seconds(&mut sim, 2.0);
+        sim.unit(id).unwrap().position
+    };
+    assert_eq!(run(7), run(7));
+    assert_ne!(run(7), run(8));
+}
@@ -0,0 +1,134 @@
+/*******************************************************************
+* ** Benchmark **
+* Runs each scenario over a range of seeds and boils the runs
+* down to one row. Failed runs count as the full time limit so
+* a solution can not look faster by dropping kills. The table
+* doubles as the baseline file format
+********************************************************************/
+use crate::scenario::{Outcome, Scenario};
+use std::fmt::Write;
+use std::ops::Range;
+
+const HEADER: &str = "# scenario      runs passed      mean    median     worst  hit_rate     shots";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub scenario: String,
+    pub runs: u32,
+    pub passed: u32,
+    pub mean: f64, // seconds
+    pub median: f64,
+    pub worst: f64,
+    pub hit_rate: f64, // hits / shots over every run
+    pub shots: f64,    // per run
+}
+
+// How much worse than the baseline still counts as noise
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    pub time: f64,     // fraction of the baseline mean/median
+    pub worst: f64,    // fraction of the baseline worst run, one seed so looser than time
+    pub hit_rate: f64, // absolute
+}
+
+impl Default for Tolerance {
+    fn default() -> Tolerance {
+        Tolerance {
+            time: 0.05,
+            worst: 0.10,
+            hit_rate: 0.02,
+        }
+    }
+}
+
+pub fn summarize(scenario: &Scenario, seeds: Range<u64>) -> Summary {
+    let outcomes: Vec<Outcome> = seeds.map(|seed| scenario.run(seed)).collect();
+    let mut times: Vec<f64> = outcomes.iter().map(|outcome| outcome.time_to_kill.unwrap_or(scenario.time_limit)).collect();
+    times.sort_by(f64::total_cmp);
+    let runs = outcomes.len().max(1);
+    let shots: u32 = outcomes.iter().map(|outcome| outcome.shots).sum();
+    let hits: u32 = outcomes.iter().map(|outcome| outcome.hits).sum();
+    Summary {
+        scenario: scenario.name.to_string(),
+        runs: outcomes.len() as u32,
+        passed: outcomes.iter().filter(|outcome| outcome.passed).count() as u32,
+        mean: times.iter().sum::<f64>() / runs as f64,
+        median: median(&times),
+        worst: times.last().copied().unwrap_or(0.0),
+        hit_rate: if shots == 0 { 0.0 } else { hits as f64 / shots as f64 },
+        shots: shots as f64 / runs as f64,
+    }
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        n if n % 2 == 1 => sorted[n / 2],
+        n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0,
+    }
+}
+
+pub fn table(summaries: &[Summary]) -> String {
+    let mut output = String::from(HEADER);
+    output.push('\n');
+    for summary in summaries {
+        let _ = writeln!(
+            output,
+            "{:<15} {:>5} {:>6} {:>9.3} {:>9.3} {:>9.3} {:>9.4} {:>9.1}",
+            summary.scenario, summary.runs, summary.passed, summary.mean, summary.median, summary.worst, summary.hit_rate, summary.shots
+        );
+    }
+    output
+}
+
+pub fn parse_table(text: &str) -> Result<Vec<Summary>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            let [scenario, runs, passed, mean, median, worst, hit_rate, shots] = columns[..] else {
+                return Err(format!("expected 8 columns in `{}`", line));
+            };
+            let number = |column: &str| column.parse::<f64>().map_err(|_| format!("bad number `{}` in `{}`", column, line));
+            Ok(Summary {
+                scenario: scenario.to_string(),
+                runs: number(runs)? as u32,
+                passed: number(passed)? as u32,
+                mean: number(mean)?,
+                median: number(median)?,
+                worst: number(worst)?,
+                hit_rate: number(hit_rate)?,
+                shots: number(shots)?,
+            })
+        })
+        .collect()
+}
+
+// Every way `current` got slower or less accurate than `baseline`. Shots are reported for
+// reference only, firing more is not worse in itself
+pub fn regressions(current: &[Summary], baseline: &[Summary], tolerance: Tolerance) -> Vec<String> {
+    let mut found = Vec::new();
+    for summary in current {
+        let Some(base) = baseline.iter().find(|base| base.scenario == summary.scenario) else {
+            continue;
+        };
+        let name = &summary.scenario;
+        if base.runs == summary.runs && summary.passed < base.passed {
+            found.push(format!("{}: passed {} of {}, baseline {}", name, summary.passed, summary.runs, base.passed));
+        }
+        if summary.mean > base.mean * (1.0 + tolerance.time) {
+            found.push(format!("{}: mean time-to-kill {:.3}s, baseline {:.3}s", name, summary.mean, base.mean));
+        }
+        if summary.median > base.median * (1.0 + tolerance.time) {
+            found.push(format!("{}: median time-to-kill {:.3}s, baseline {:.3}s", name, summary.median, base.median));
+        }
+        if summary.worst > base.worst * (1.0 + tolerance.worst) {
+            found.push(format!("{}: worst time-to-kill {:.3}s, baseline {:.3}s", name, summary.worst, base.worst));
+        }
+        if summary.hit_rate < base.hit_rate - tolerance.hit_rate {
+            found.push(format!("{}: hit rate {:.4}, baseline {:.4}", name, summary.hit_rate, base.hit_rate));
+        }
+    }
+    found
+}
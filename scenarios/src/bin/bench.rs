@@ -0,0 +1,88 @@
+/**************************************************************
+* bench
+* Author: Christopher Dean
+* Runs every tutorial scenario over seeds 0..runs, prints the
+* results table and compares it against the stored baseline.
+* Exits non-zero when anything regressed past tolerance.
+*
+* usage: bench [--runs <n>] [--baseline <file>] [--write-baseline]
+*              [--tolerance <fraction>] [-o <results file>]
+****************************************************************/
+use scenarios::benchmark::{parse_table, regressions, summarize, table, Tolerance};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::{env, fs};
+
+const USAGE: &str = "usage: bench [--runs <n>] [--baseline <file>] [--write-baseline] [--tolerance <fraction>] [-o <results file>]";
+const DEFAULT_RUNS: u64 = 20;
+
+struct Options {
+    runs: u64,
+    baseline: PathBuf,
+    write_baseline: bool,
+    tolerance: Tolerance,
+    output: Option<PathBuf>,
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        runs: DEFAULT_RUNS,
+        baseline: PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("baseline.txt"),
+        write_baseline: false,
+        tolerance: Tolerance::default(),
+        output: None,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => options.runs = args.next().and_then(|value| value.parse().ok()).ok_or("--runs needs a number")?,
+            "--baseline" => options.baseline = args.next().ok_or("--baseline needs a file")?.into(),
+            "--write-baseline" => options.write_baseline = true,
+            "--tolerance" => options.tolerance.time = args.next().and_then(|value| value.parse().ok()).ok_or("--tolerance needs a fraction")?,
+            "-o" | "--output" => options.output = Some(args.next().ok_or("-o needs a file")?.into()),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    Ok(options)
+}
+
+fn write(path: &PathBuf, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|error| format!("failed to write {}: {}", path.display(), error))
+}
+
+fn run() -> Result<bool, String> {
+    let options = parse_options(env::args().skip(1))?;
+    let summaries: Vec<_> = scenarios::all().iter().map(|scenario| summarize(scenario, 0..options.runs)).collect();
+    let results = table(&summaries);
+    print!("{}", results);
+    if let Some(output) = &options.output {
+        write(output, &results)?;
+    }
+    if options.write_baseline {
+        write(&options.baseline, &results)?;
+        println!("baseline written to {}", options.baseline.display());
+        return Ok(true);
+    }
+    let baseline = match fs::read_to_string(&options.baseline) {
+        Ok(text) => parse_table(&text)?,
+        Err(_) => {
+            println!("no baseline at {}, nothing to compare", options.baseline.display());
+            return Ok(true);
+        }
+    };
+    let found = regressions(&summaries, &baseline, options.tolerance);
+    for regression in &found {
+        println!("REGRESSION {}", regression);
+    }
+    Ok(found.is_empty())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/**************************************************************
+* turns
+* Author: Christopher Dean
+* Flies every heading controller on each ship class onto a
+* set of fixed marks and prints settle time and overshoot.
+*
+* usage: turns
+****************************************************************/
+use scenarios::turning::{compare, turn_table};
+
+fn main() {
+    print!("{}", turn_table(&compare()));
+}
@@ -0,0 +1,181 @@
+/*******************************************************************
+* ** Tutorial Catalog **
+* Tutorials 5-11 as close as we can get them to the game: the
+* same information is available to the Ship (target(), radar
+* only, radio feed) and the enemies move the same way. Spawn
+* positions are drawn from the seed
+********************************************************************/
+use crate::scenario::{Scenario, ENEMY, PLAYER};
+use crate::scripts::{Beacon, Dogfighter, Jinking};
+use oort_api::mock::Limits;
+use oort_api::prelude::*;
+use oort_core::radio::POSITIONING_CHANNEL;
+use oort_sim::random::Random;
+use oort_sim::{factory, scripted, Factory, Simulation, UnitSpec};
+use tutorials::{deflection, lead, missiles, radar, radio, search, squadron};
+
+pub fn all() -> Vec<Scenario> {
+    vec![
+        Scenario {
+            name: "lead",
+            tutorial: 5,
+            time_limit: 60.0,
+            player: || factory(lead::Ship::new, lead::Ship::tick),
+            setup: setup_lead,
+        },
+        Scenario {
+            name: "deflection",
+            tutorial: 6,
+            time_limit: 60.0,
+            player: || factory(deflection::Ship::new, deflection::Ship::tick),
+            setup: setup_deflection,
+        },
+        Scenario {
+            name: "radar",
+            tutorial: 7,
+            time_limit: 60.0,
+            player: || factory(radar::Ship::new, radar::Ship::tick),
+            setup: setup_radar,
+        },
+        Scenario {
+            name: "search",
+            tutorial: 8,
+            time_limit: 90.0,
+            player: || factory(search::Ship::new, search::Ship::tick),
+            setup: setup_search,
+        },
+        Scenario {
+            name: "radio",
+            tutorial: 9,
+            time_limit: 90.0,
+            player: || factory(radio::Ship::new, radio::Ship::tick),
+            setup: setup_radio,
+        },
+        Scenario {
+            name: "missiles",
+            tutorial: 10,
+            time_limit: 90.0,
+            player: || factory(missiles::Ship::new, missiles::Ship::tick),
+            setup: setup_missiles,
+        },
+        Scenario {
+            name: "squadron",
+            tutorial: 11,
+            time_limit: 120.0,
+            player: || factory(squadron::Ship::new, squadron::Ship::tick),
+            setup: setup_squadron,
+        },
+    ]
+}
+
+pub fn find(name: &str) -> Option<Scenario> {
+    all().into_iter().find(|scenario| scenario.name == name)
+}
+
+// Somewhere on a ring around the origin
+fn ring_position(random: &mut Random, min_distance: f64, max_distance: f64) -> Vec2 {
+    vec2(random.range(min_distance, max_distance), 0.0).rotate(random.range(0.0, TAU))
+}
+
+fn drift_velocity(random: &mut Random, max_speed: f64) -> Vec2 {
+    vec2(random.range(0.0, max_speed), 0.0).rotate(random.range(0.0, TAU))
+}
+
+fn spawn_player(sim: &mut Simulation, player: &Factory) -> u32 {
+    sim.spawn(UnitSpec::create(PLAYER, Class::Fighter, vec2(0.0, 0.0)).with_controller(player.clone()))
+}
+
+// Tutorial 5: a drifting target, target() is given and the player may only turn
+fn setup_lead(sim: &mut Simulation, player: &Factory) {
+    let mut random = Random::create(sim.seed());
+    let turn_only = Limits {
+        max_forward_acceleration: 0.0,
+        max_backward_acceleration: 0.0,
+        max_lateral_acceleration: 0.0,
+        ..Limits::for_class(Class::Fighter)
+    };
+    let ship = sim.spawn(
+        UnitSpec::create(PLAYER, Class::Fighter, vec2(0.0, 0.0))
+            .with_limits(turn_only)
+            .with_controller(player.clone()),
+    );
+    let enemy =
+        sim.spawn(UnitSpec::create(ENEMY, Class::Fighter, ring_position(&mut random, 1000.0, 2000.0)).with_velocity(drift_velocity(&mut random, 200.0)));
+    sim.set_target(ship, enemy);
+}
+
+// Tutorial 6: the target jinks, target() is still given
+fn setup_deflection(sim: &mut Simulation, player: &Factory) {
+    let mut random = Random::create(sim.seed());
+    let ship = spawn_player(sim, player);
+    let enemy = sim.spawn(
+        UnitSpec::create(ENEMY, Class::Fighter, ring_position(&mut random, 1000.0, 2000.0))
+            .with_velocity(drift_velocity(&mut random, 200.0))
+            .with_controller(scripted(Jinking::create(60, 30.0))),
+    );
+    sim.set_target(ship, enemy);
+}
+
+// Tutorial 7: as Deflection, but the target has to be found with the radar
+fn setup_radar(sim: &mut Simulation, player: &Factory) {
+    let mut random = Random::create(sim.seed());
+    spawn_player(sim, player);
+    sim.spawn(
+        UnitSpec::create(ENEMY, Class::Fighter, ring_position(&mut random, 1000.0, 2000.0))
+            .with_velocity(drift_velocity(&mut random, 200.0))
+            .with_controller(scripted(Jinking::create(60, 30.0))),
+    );
+}
+
+// Tutorial 8: the target starts far away
+fn setup_search(sim: &mut Simulation, player: &Factory) {
+    let mut random = Random::create(sim.seed());
+    spawn_player(sim, player);
+    sim.spawn(
+        UnitSpec::create(ENEMY, Class::Fighter, ring_position(&mut random, 5000.0, 9000.0))
+            .with_velocity(drift_velocity(&mut random, 100.0))
+            .with_controller(scripted(Jinking::create(120, 20.0))),
+    );
+}
+
+// Tutorial 9: as Search, with the target broadcast on the positioning channel
+fn setup_radio(sim: &mut Simulation, player: &Factory) {
+    let mut random = Random::create(sim.seed());
+    spawn_player(sim, player);
+    let enemy = sim.spawn(
+        UnitSpec::create(ENEMY, Class::Fighter, ring_position(&mut random, 5000.0, 9000.0))
+            .with_velocity(drift_velocity(&mut random, 100.0))
+            .with_controller(scripted(Jinking::create(120, 20.0))),
+    );
+    let beacon =
+        sim.spawn(UnitSpec::create(PLAYER, Class::Target, vec2(-15000.0, -15000.0)).with_controller(scripted(Beacon { channel: POSITIONING_CHANNEL })));
+    sim.set_target(beacon, enemy);
+}
+
+// Tutorial 10: a jinking fighter at missile range
+fn setup_missiles(sim: &mut Simulation, player: &Factory) {
+    let mut random = Random::create(sim.seed());
+    spawn_player(sim, player);
+    sim.spawn(
+        UnitSpec::create(ENEMY, Class::Fighter, ring_position(&mut random, 3000.0, 5000.0))
+            .with_velocity(drift_velocity(&mut random, 100.0))
+            .with_controller(scripted(Jinking::create(60, 30.0))),
+    );
+}
+
+// Tutorial 11: three fighters a side, and the enemy shoots back
+fn setup_squadron(sim: &mut Simulation, player: &Factory) {
+    let mut random = Random::create(sim.seed());
+    for slot in [-1.0, 0.0, 1.0] {
+        let position = vec2(-2000.0, slot * 500.0);
+        sim.spawn(UnitSpec::create(PLAYER, Class::Fighter, position).with_controller(player.clone()));
+    }
+    for slot in [-1.0, 0.0, 1.0] {
+        let position = vec2(random.range(4000.0, 6000.0), slot * 1000.0 + random.range(-200.0, 200.0));
+        sim.spawn(
+            UnitSpec::create(ENEMY, Class::Fighter, position)
+                .with_heading(PI)
+                .with_controller(scripted(Dogfighter::default())),
+        );
+    }
+}
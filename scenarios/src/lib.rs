@@ -0,0 +1,15 @@
+/**************************************************************
+* scenarios
+* Author: Christopher Dean
+* The oort.rs tutorials rebuilt for oort_sim, so every
+* tutorial Ship can be run headless and scored on pass/fail
+* and time-to-kill.
+****************************************************************/
+pub mod benchmark;
+pub mod catalog;
+pub mod scenario;
+pub mod scripts;
+pub mod turning;
+
+pub use catalog::{all, find};
+pub use scenario::{Outcome, Scenario, ENEMY, PLAYER};
@@ -0,0 +1,51 @@
+/**************************************************************
+* scenarios
+* Author: Christopher Dean
+* Runs tutorial scenarios headless and prints pass/fail and
+* time-to-kill for each one.
+*
+* usage: scenarios [name ...] [--seed <n>]
+****************************************************************/
+use std::env;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: scenarios [name ...] [--seed <n>]";
+
+fn run() -> Result<bool, String> {
+    let mut names = Vec::new();
+    let mut seed = 0;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => seed = args.next().and_then(|value| value.parse().ok()).ok_or("--seed needs a number")?,
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => names.push(arg),
+        }
+    }
+    let scenarios = if names.is_empty() {
+        scenarios::all()
+    } else {
+        names
+            .iter()
+            .map(|name| scenarios::find(name).ok_or(format!("unknown scenario `{}`\n{}", name, USAGE)))
+            .collect::<Result<_, _>>()?
+    };
+    let mut all_passed = true;
+    for scenario in scenarios {
+        let outcome = scenario.run(seed);
+        all_passed &= outcome.passed;
+        println!("Tutorial {:<3} {}", scenario.tutorial, outcome);
+    }
+    Ok(all_passed)
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
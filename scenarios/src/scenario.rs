@@ -0,0 +1,82 @@
+/*******************************************************************
+* ** Scenario **
+* A tutorial setup: who spawns where, which Ship the player
+* runs, and how long it has. The win condition is the game's:
+* every enemy ship destroyed while the player still has one
+********************************************************************/
+use oort_api::prelude::*;
+use oort_sim::{Factory, Simulation, Team};
+use std::fmt;
+
+pub const PLAYER: Team = 0;
+pub const ENEMY: Team = 1;
+
+pub struct Scenario {
+    pub name: &'static str,
+    pub tutorial: u32,
+    pub time_limit: f64, // seconds
+    pub player: fn() -> Factory,
+    pub setup: fn(&mut Simulation, &Factory),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outcome {
+    pub scenario: &'static str,
+    pub seed: u64,
+    pub passed: bool,
+    pub time_to_kill: Option<f64>, // seconds until the last enemy died
+    pub elapsed: f64,
+    pub shots: u32,
+    pub hits: u32,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.passed { "PASS" } else { "FAIL" };
+        match self.time_to_kill {
+            Some(time) => write!(f, "{:<12} seed {:<6} {} {:.3}s", self.scenario, self.seed, verdict, time),
+            None => write!(f, "{:<12} seed {:<6} {} (gave up at {:.3}s)", self.scenario, self.seed, verdict, self.elapsed),
+        }
+    }
+}
+
+impl Scenario {
+    pub fn limit_ticks(&self) -> u32 {
+        (self.time_limit / TICK_LENGTH).round() as u32
+    }
+
+    pub fn build(&self, seed: u64) -> Simulation {
+        let mut sim = Simulation::create(seed);
+        (self.setup)(&mut sim, &(self.player)());
+        sim
+    }
+
+    pub fn run(&self, seed: u64) -> Outcome {
+        let mut sim = self.build(seed);
+        sim.run_until(self.limit_ticks(), |sim| sim.ships_alive(ENEMY) == 0 || sim.ships_alive(PLAYER) == 0);
+        self.outcome(&sim)
+    }
+
+    pub fn outcome(&self, sim: &Simulation) -> Outcome {
+        let passed = sim.ships_alive(ENEMY) == 0 && sim.ships_alive(PLAYER) > 0;
+        let time_to_kill = passed.then(|| {
+            let last_kill = sim
+                .destroyed()
+                .iter()
+                .filter(|destroyed| destroyed.team == ENEMY)
+                .map(|destroyed| destroyed.tick)
+                .max();
+            // the kill lands during the tick, so count it as a full one like the game clock does
+            last_kill.map_or(0.0, |tick| (tick + 1) as f64 * TICK_LENGTH)
+        });
+        Outcome {
+            scenario: self.name,
+            seed: sim.seed(),
+            passed,
+            time_to_kill,
+            elapsed: sim.time(),
+            shots: sim.tally(PLAYER).shots,
+            hits: sim.tally(PLAYER).hits,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+/*******************************************************************
+* ** Scripted Opponents **
+* Stand-ins for the game's tutorial AI. They run through the
+* same oort_api calls as our Ships, so rand() draws from the
+* unit's own seeded stream and every run replays exactly
+********************************************************************/
+use oort_api::prelude::*;
+use oort_core::constants::BULLET_SPEED;
+use oort_sim::Controller;
+
+/*****************************************************
+* Jinking
+* Picks a new random acceleration every interval
+* ticks, the Deflection/Radar/Search target
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jinking {
+    pub interval: u32,
+    pub acceleration: f64,
+    current: Vec2,
+}
+
+impl Jinking {
+    pub fn create(interval: u32, acceleration: f64) -> Jinking {
+        Jinking {
+            interval,
+            acceleration,
+            current: vec2(0.0, 0.0),
+        }
+    }
+}
+
+impl Controller for Jinking {
+    fn tick(&mut self) {
+        if current_tick().is_multiple_of(self.interval) {
+            self.current = vec2(rand(-1.0, 1.0), rand(-1.0, 1.0)) * self.acceleration;
+        }
+        accelerate(self.current);
+    }
+}
+
+/*****************************************************
+* Beacon
+* Broadcasts its target() as [position, velocity] on
+* a radio channel every tick, the Radio tutorial feed
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Beacon {
+    pub channel: usize,
+}
+
+impl Controller for Beacon {
+    fn tick(&mut self) {
+        let (position, velocity) = (target(), target_velocity());
+        set_radio_channel(self.channel);
+        send([position.x, position.y, velocity.x, velocity.y]);
+    }
+}
+
+/*****************************************************
+* Dogfighter
+* Sweeps for a contact, locks it, turns onto a first
+* order lead and fires once roughly aligned. Loose on
+* purpose, the Squadron tutorial AI is not a sniper
+*******************************************************/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dogfighter {
+    pub fire_range: f64,
+    pub fire_tolerance: f64,
+    pub standoff: f64,
+}
+
+impl Default for Dogfighter {
+    fn default() -> Dogfighter {
+        Dogfighter {
+            fire_range: 1500.0,
+            fire_tolerance: 0.08,
+            standoff: 800.0,
+        }
+    }
+}
+
+impl Controller for Dogfighter {
+    fn tick(&mut self) {
+        let Some(contact) = scan() else {
+            set_radar_heading(radar_heading() + TAU / 60.0);
+            torque(-angular_velocity() * 10.0);
+            accelerate(vec2(0.0, 0.0));
+            return;
+        };
+        let offset = contact.position - position();
+        set_radar_heading(offset.angle());
+        let time_of_flight = offset.length() / BULLET_SPEED;
+        let lead = offset + (contact.velocity - velocity()) * time_of_flight;
+        let error = angle_diff(heading(), lead.angle());
+        turn(error * 6.0);
+        if error.abs() < self.fire_tolerance && offset.length() < self.fire_range {
+            fire(0);
+        }
+        let closing = if offset.length() > self.standoff {
+            offset.normalize() * 60.0
+        } else {
+            -velocity()
+        };
+        accelerate(closing);
+    }
+}
@@ -0,0 +1,113 @@
+/*******************************************************************
+* ** Turn Comparison **
+* Flies every HeadingController on a lone ship of each class
+* onto fixed marks and reports how long the nose takes to
+* settle and how far it swings past the mark on the way. The
+* ship starts at rest, so the numbers are the controller's own
+********************************************************************/
+use oort_api::prelude::*;
+use oort_core::turning::{BangBangTurn, HeadingController, PdTurn, CASTLE_TURN, DEFLECTION_TURN, FIGHTER_TURN, SEAHORSE_TURN};
+use oort_sim::{scripted, Controller, Simulation, UnitSpec};
+use std::fmt::Write;
+use std::rc::Rc;
+
+const HEADER: &str = "# controller  class        angle    settle  overshoot";
+pub const SETTLE_ANGLE: f64 = 0.001; // radians, held inside this until the end counts as settled
+pub const TURN_ANGLES: [f64; 4] = [0.1, 0.5, PI / 2.0, 3.0];
+pub const TURN_CLASSES: [Class; 3] = [Class::Fighter, Class::Frigate, Class::Cruiser];
+const TURN_TICKS: u32 = 60 * 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TurnResult {
+    pub controller: &'static str,
+    pub class: Class,
+    pub angle: f64,               // radians to the mark at the start
+    pub settle_time: Option<f64>, // seconds, None if it never settled
+    pub overshoot: f64,           // radians past the mark at the furthest
+}
+
+// The controllers compared, by name. Every tuning a tutorial flies is here: bang-bang on
+// the lead tutorial and the castle capital ships, hybrid on the fighter tutorials, and
+// the deflection and castle hybrids on their own ships
+pub fn controllers() -> Vec<(&'static str, Rc<dyn HeadingController>)> {
+    vec![
+        ("pd", Rc::new(PdTurn::create(40.0, 6.0))),
+        ("damped", Rc::new(SEAHORSE_TURN)),
+        ("bang-bang", Rc::new(BangBangTurn)),
+        ("hybrid", Rc::new(FIGHTER_TURN)),
+        ("deflection", Rc::new(DEFLECTION_TURN)),
+        ("castle", Rc::new(CASTLE_TURN)),
+    ]
+}
+
+// Holds the nose on one fixed mark
+#[derive(Clone)]
+struct Turner {
+    controller: Rc<dyn HeadingController>,
+    mark: f64,
+}
+
+impl Controller for Turner {
+    fn tick(&mut self) {
+        torque(self.controller.torque(angle_diff(heading(), self.mark)));
+    }
+}
+
+// Turn a ship of `class` from heading 0 onto `angle`
+pub fn fly(name: &'static str, controller: Rc<dyn HeadingController>, class: Class, angle: f64) -> TurnResult {
+    let mut sim = Simulation::create(0);
+    let spec = UnitSpec::create(0, class, vec2(0.0, 0.0)).with_controller(scripted(Turner { controller, mark: angle }));
+    let id = sim.spawn(spec);
+    let (mut last_outside, mut overshoot) = (None, 0.0_f64);
+    for tick in 0..TURN_TICKS {
+        sim.step();
+        let error = angle_diff(sim.unit(id).map_or(0.0, |unit| unit.heading), angle);
+        overshoot = overshoot.max(-error * angle.signum());
+        if error.abs() > SETTLE_ANGLE {
+            last_outside = Some(tick);
+        }
+    }
+    let settle_time = match last_outside {
+        Some(tick) if tick + 1 == TURN_TICKS => None,
+        Some(tick) => Some((tick + 1) as f64 * TICK_LENGTH),
+        None => Some(0.0),
+    };
+    TurnResult {
+        controller: name,
+        class,
+        angle,
+        settle_time,
+        overshoot,
+    }
+}
+
+// Every controller against every class and angle
+pub fn compare() -> Vec<TurnResult> {
+    let mut results = Vec::new();
+    for (name, controller) in controllers() {
+        for class in TURN_CLASSES {
+            for angle in TURN_ANGLES {
+                results.push(fly(name, controller.clone(), class, angle));
+            }
+        }
+    }
+    results
+}
+
+pub fn turn_table(results: &[TurnResult]) -> String {
+    let mut output = String::from(HEADER);
+    output.push('\n');
+    for result in results {
+        let settle = result.settle_time.map_or("never".to_string(), |time| format!("{:.3}", time));
+        let _ = writeln!(
+            output,
+            "{:<13} {:<10} {:>7.3} {:>9} {:>10.4}",
+            result.controller,
+            format!("{:?}", result.class),
+            result.angle,
+            settle,
+            result.overshoot
+        );
+    }
+    output
+}
@@ -0,0 +1,66 @@
+/**************************************************************
+* Benchmark tests
+* The results table doubles as the baseline file, so it has to
+* round trip, and the comparison has to catch regressions
+* without tripping on noise
+****************************************************************/
+use scenarios::benchmark::{parse_table, regressions, summarize, table, Summary, Tolerance};
+
+fn summary(mean: f64, hit_rate: f64, passed: u32) -> Summary {
+    Summary {
+        scenario: "lead".to_string(),
+        runs: 10,
+        passed,
+        mean,
+        median: mean,
+        worst: mean * 2.0,
+        hit_rate,
+        shots: 120.5,
+    }
+}
+
+#[test]
+fn table_round_trips() {
+    let summaries = vec![
+        summary(12.5, 0.125, 9),
+        Summary {
+            scenario: "radio".to_string(),
+            ..summary(20.25, 0.0625, 10)
+        },
+    ];
+    assert_eq!(parse_table(&table(&summaries)).unwrap(), summaries);
+}
+
+#[test]
+fn malformed_rows_are_rejected() {
+    assert!(parse_table("lead 10 9 12.5").is_err());
+    assert!(parse_table("lead 10 nine 1 1 1 1 1").is_err());
+}
+
+#[test]
+fn regressions_flag_slower_and_less_accurate_runs() {
+    let baseline = vec![summary(10.0, 0.2, 10)];
+    let tolerance = Tolerance::default();
+
+    assert!(regressions(&[summary(10.4, 0.19, 10)], &baseline, tolerance).is_empty());
+    assert!(regressions(&[summary(8.0, 0.3, 10)], &baseline, tolerance).is_empty());
+    assert_eq!(regressions(&[summary(10.6, 0.2, 10)], &baseline, tolerance).len(), 2, "mean and median");
+    assert_eq!(regressions(&[summary(10.0, 0.1, 10)], &baseline, tolerance).len(), 1);
+    assert_eq!(regressions(&[summary(10.0, 0.2, 9)], &baseline, tolerance).len(), 1);
+    let slow_seed = Summary {
+        worst: 23.0,
+        ..summary(10.0, 0.2, 10)
+    };
+    assert_eq!(regressions(&[slow_seed], &baseline, tolerance).len(), 1, "worst");
+}
+
+#[test]
+fn summary_counts_failures_at_the_time_limit() {
+    let scenario = scenarios::find("lead").unwrap();
+    let result = summarize(&scenario, 0..4);
+    assert_eq!(result.runs, 4);
+    assert!(result.worst <= scenario.time_limit);
+    if result.passed < result.runs {
+        assert_eq!(result.worst, scenario.time_limit);
+    }
+}
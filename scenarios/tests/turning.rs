@@ -0,0 +1,36 @@
+/**************************************************************
+* Turn comparison tests
+* The bang-bang turn is the minimum time turn, so no other
+* controller may settle sooner, and the harness has to report
+* every controller on every class and mark
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::turning::BangBangTurn;
+use scenarios::turning::{compare, fly, turn_table, TURN_ANGLES, TURN_CLASSES};
+use std::rc::Rc;
+
+#[test]
+fn bang_bang_settles_first_without_overshoot() {
+    let results = compare();
+    for class in TURN_CLASSES {
+        for angle in TURN_ANGLES {
+            let bang_bang = fly("bang-bang", Rc::new(BangBangTurn), class, angle);
+            let settle = bang_bang.settle_time.expect("bang-bang always settles");
+            assert!(bang_bang.overshoot < 1e-3, "{class:?} {angle}: {bang_bang:?}");
+            for other in results.iter().filter(|result| result.class == class && result.angle == angle) {
+                assert!(
+                    other.settle_time.is_none_or(|time| time >= settle - TICK_LENGTH),
+                    "{other:?} beat {bang_bang:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn table_has_a_row_per_run() {
+    let results = vec![fly("bang-bang", Rc::new(BangBangTurn), Class::Frigate, 0.5)];
+    let table = turn_table(&results);
+    assert_eq!(table.lines().count(), 2);
+    assert!(table.lines().nth(1).unwrap().starts_with("bang-bang     Frigate"));
+}
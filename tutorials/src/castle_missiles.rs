@@ -0,0 +1,822 @@
+// Tutorial: Missiles
+// Destroy the enemy ship with your missiles.
+// Hint: https://en.wikipedia.org/wiki/Proportional_navigation
+
+/**************************************************************
+* Tutorial 10: Radio
+* Author: Christopher Dean
+* Last Update: 11/08/23
+* Adjusted to using the radio on channel 2 to supply position
+* and velocity
+* Completely rebuilt organization with multiple ship
+* sub types
+* 25.717s on tutorial 10
+****************************************************************/
+use oort_api::prelude::*;
+use oort_core::blackboard::Blackboard;
+use oort_core::constants::{BULLET_SPEED, MISSILE_RELOAD_TIME, MISSILE_SPEED, TICKS_PER_SECOND, TORPEDO_BLAST_RADIUS};
+use oort_core::diagnostics;
+use oort_core::envelope::{MissileEnergy, MissilePhase};
+use oort_core::fuse::ProximityFuse;
+use oort_core::guidance::{Guidance, PnLaw};
+use oort_core::intercept::{intercept_point, solve_intercept, Projectile};
+use oort_core::kalman::{scan_noise, ContactFilter, MeasurementNoise};
+use oort_core::navigation::{approach_and_orbit, update_engine_vectors};
+use oort_core::radar::{RadarData, RadarScheduler, SweepRadar, SWEEP_STEP};
+use oort_core::radio::{MessageID, RadioManager, RadioMessage, RadioPurpose};
+use oort_core::targeting::{AccelerationLead, LeadTracker, UnitDescription};
+use oort_core::turning::{heading_controller, mark_rate, HeadingController, CASTLE_TURN};
+use oort_core::weapons::{allocate_turrets, BurstGun, Mount, Salvo, WeaponSlot};
+use std::collections::VecDeque;
+
+// What every subsystem of this ship can see, lent to each sub type's tick
+pub struct SharedData {
+    pub blackboard: Blackboard,
+}
+
+impl SharedData {
+    pub fn create() -> SharedData {
+        SharedData {
+            blackboard: Blackboard::create(),
+        }
+    }
+}
+
+/******************************************************************************************
+* ** Fighter Type **
+*
+******************************************************************************************/
+pub struct TyFighter {
+    radar: SweepRadar,
+    target: Option<UnitDescription>,
+    lost_target: Option<UnitDescription>,
+    radio: RadioManager,
+    lead: LeadTracker,
+    gun1: BurstGun,
+    acceleration: Vec2,
+    turn: Box<dyn HeadingController>,
+}
+
+impl TyFighter {
+    pub fn configure_fighter() -> Ship {
+        Ship {
+            cruiser_data: None,
+            frigate_data: None,
+            fighter_data: Some(TyFighter {
+                radar: SweepRadar::create(SWEEP_STEP).with_sweep_distance(MISSILE_SPEED * 10.0),
+                target: None,
+                lost_target: None,
+                radio: RadioManager::for_class(Class::Fighter, &[RadioPurpose::FleetContacts, RadioPurpose::MissileUplink]),
+                lead: LeadTracker::create(Projectile::create(MISSILE_SPEED), AccelerationLead::Ignore),
+                gun1: BurstGun::create(1, 16, 2),
+                acceleration: vec2(0.0, 0.0),
+                turn: Box::new(CASTLE_TURN),
+            }),
+            missle_data: None,
+            shared_data: SharedData::create(),
+        }
+    }
+
+    // Steer our missiles onto the target, they work out their own lead. A dropped
+    // target is reported once so the fleet can look for it, otherwise our tracks
+    // go out in turn
+    pub fn send_radio(&mut self, board: &mut Blackboard) {
+        if let Some(target) = self.target.as_ref() {
+            let message = RadioMessage::create(MessageID::TargetUpdate, target.id.unwrap_or_default(), target.position, target.velocity);
+            self.radio.queue(RadioPurpose::MissileUplink, message.with_class(target.class));
+        }
+        match self.lost_target.take().filter(|_| self.target.is_none()) {
+            Some(lost) => {
+                let message = RadioMessage::create(MessageID::TrackLost, lost.id.unwrap_or_default(), lost.position, lost.velocity);
+                self.radio.queue(RadioPurpose::FleetContacts, message.with_class(lost.class));
+            }
+            None => board.fleet.share(&board.tracks, &mut self.radio),
+        }
+    }
+
+    pub fn update_radar(&mut self, board: &mut Blackboard) {
+        let lock_heading = self.target.map(|target| (target.position - position()).angle());
+        self.radar.update(lock_heading);
+        self.find_target(board);
+    }
+
+    // Stay on the current track while it lives, otherwise take the nearest firm one,
+    // which may be a track the fleet reported that our radar has not found
+    pub fn find_target(&mut self, board: &mut Blackboard) {
+        if let Some(contact) = self.radar.contact.as_ref() {
+            board.tracks.ingest(contact);
+        }
+        board.fleet.merge(&mut board.tracks, &self.radio);
+        let now = current_time();
+        board.tracks.update(now);
+        let locked = self
+            .target
+            .and_then(|target| target.id)
+            .and_then(|id| board.tracks.get(id))
+            .filter(|track| track.is_firm());
+        let track = locked.or_else(|| board.tracks.nearest(position(), now)).copied();
+        if track.map(|track| track.id) != self.target.and_then(|target| target.id) {
+            self.lead.reset();
+            self.lost_target = self.target;
+        }
+        self.target = track.map(|track| {
+            let description = track.describe(now);
+            let lead_position = self.lead.track(description.position, description.velocity, velocity());
+            UnitDescription::create(track.class, description.position, description.velocity, lead_position).with_id(track.id)
+        });
+        self.gun1.should_fire = match self.target.as_ref() {
+            Some(target) => {
+                angle_diff(heading(), target.target_heading).abs() < 0.2
+                    && MissileEnergy::missile().no_escape(position(), velocity(), target.position, target.velocity, target.class)
+            }
+            None => false,
+        };
+    }
+
+    /*****************************************************************************************************************
+     * ** Diagnostics **
+     * Functions used to update the systems diagnostics
+     ******************************************************************************************************************/
+    pub fn draw_diagnostics(&self) {
+        diagnostics::draw_ship_state();
+        if let Some(target) = self.target.as_ref() {
+            diagnostics::draw_target_heading(Some(target.target_heading));
+            diagnostics::draw_lead(target.lead_position, MISSILE_SPEED, self.lead.target_acceleration);
+        }
+        diagnostics::draw_target_line();
+    }
+
+    /********************************************************************************************************
+     * ** Mission Specific functions **
+     * Functions used to update the systems diagnostics
+     *********************************************************************************************************/
+    pub fn tick(&mut self, shared: &mut SharedData) {
+        self.radio.listen();
+        self.update_radar(&mut shared.blackboard);
+        self.send_radio(&mut shared.blackboard);
+        let mut torque_val = 0.0;
+        if let Some(target) = self.target.as_ref() {
+            if let Some(lead_position) = target.lead_position {
+                self.acceleration = approach_and_orbit(600.0, 950.0, position(), target.position, target.velocity);
+                let angle_to_mark = angle_diff(heading(), (lead_position - position()).angle());
+                torque_val = self.turn.track(angle_to_mark, mark_rate(lead_position, target.velocity));
+            } else {
+                self.acceleration = vec2(0.0, 0.0);
+            }
+        }
+        self.gun1.update();
+        update_engine_vectors(torque_val, Some(self.acceleration));
+        self.radio.transmit();
+        self.draw_diagnostics();
+    }
+}
+
+/******************************************************************************************
+* ** Missile Type **
+*
+*
+******************************************************************************************/
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MissileStrategy {
+    Initialize,
+    FindTarget,
+    ApproachTrajectory,
+    SeekToKill,
+    GoBoom,
+}
+
+const POI_DWELL_TICKS: u64 = 20;
+const POI_RADIUS: f64 = 150.0; // meters
+
+pub struct XMissle {
+    target: Option<UnitDescription>,
+    strategy: MissileStrategy,
+    radar: RadarData,
+    missile_ticks: u32,
+    last_target: Option<UnitDescription>,
+    filter: Option<ContactFilter>, // smoothed target track
+    guidance: Guidance,
+    phase: MissilePhase,
+    fuse: ProximityFuse,
+    radio: RadioManager,
+}
+
+impl XMissle {
+    // Initialize Ship->XMissle
+    pub fn configure_missle() -> Ship {
+        set_radar_width(PI / 16.0);
+        set_radar_heading(heading());
+        Ship {
+            cruiser_data: None,
+            frigate_data: None,
+            fighter_data: None,
+            missle_data: Some(XMissle {
+                target: None,
+                strategy: MissileStrategy::Initialize,
+                radar: RadarData::create(),
+                missile_ticks: 0,
+                last_target: None,
+                filter: None,
+                guidance: Guidance::create(PnLaw::Augmented).with_max_acceleration(max_forward_acceleration()),
+                phase: MissilePhase::Boost,
+                fuse: match class() {
+                    Class::Torpedo => ProximityFuse::create().with_blast_radius(TORPEDO_BLAST_RADIUS),
+                    _ => ProximityFuse::create(),
+                },
+                radio: RadioManager::for_class(class(), &[RadioPurpose::MissileUplink]),
+            }),
+            shared_data: SharedData::create(),
+        }
+    }
+
+    // The missile is its own projectile: it closes at its current speed (never less than MISSILE_SPEED)
+    // on the filtered track, leading the target's smoothed velocity and acceleration
+    pub fn track(&self, velocity: Vec2) -> Option<Vec2> {
+        let filter = self.filter?;
+        let projectile = Projectile::create(velocity.length().max(MISSILE_SPEED)).without_inherited_velocity();
+        solve_intercept(
+            position(),
+            velocity,
+            filter.position(),
+            filter.velocity(),
+            self.target_acceleration(),
+            &projectile,
+        )
+        .map(|solution| solution.aim_point)
+    }
+
+    fn update_target(&mut self, class: Class, position: Vec2, velocity: Vec2, noise: MeasurementNoise) {
+        let now = current_time();
+        // a measurement nowhere near the track is a new target
+        let filter = match self.filter.as_mut() {
+            Some(filter) if (filter.extrapolate(now - filter.time) - position).length() < POI_RADIUS => {
+                filter.update(position, velocity, noise, now);
+                *filter
+            }
+            _ => *self.filter.insert(ContactFilter::create(position, velocity, noise, now)),
+        };
+        let lead_position = self.track(oort_api::prelude::velocity());
+        self.last_target = self.target;
+        self.target = Some(UnitDescription::create(class, filter.position(), filter.velocity(), lead_position));
+        self.radar.update_narrow_scan(filter.position(), filter.velocity());
+    }
+
+    pub fn check_for_target(&mut self) {
+        // if no target, it has not even received the ships 'initial' target yet, check radio
+        if let Some(message) = self.radio.receive(RadioPurpose::MissileUplink) {
+            if matches!(message.id, MessageID::Contact | MessageID::TargetUpdate) {
+                self.update_target(Class::Unknown, message.extrapolated_position(), message.velocity, MeasurementNoise::exact());
+            }
+        }
+    }
+
+    // Radar Targets and POI Acquisition
+    fn initialize(&mut self) {
+        self.find_target();
+        self.strategy = MissileStrategy::FindTarget;
+        self.check_for_target();
+    }
+
+    fn find_target(&mut self) {
+        // look for target and then update radar
+        if let Some(scanned_target) = scan() {
+            self.update_target(
+                scanned_target.class,
+                scanned_target.position,
+                scanned_target.velocity,
+                scan_noise(&scanned_target),
+            );
+            if self.strategy == MissileStrategy::FindTarget {
+                self.strategy = MissileStrategy::ApproachTrajectory;
+            }
+        } else if let Some(lost) = self.target.filter(|_| !self.radar.poi_expired(POI_DWELL_TICKS)) {
+            // look where the target should have drifted to before giving up on it
+            let predicted = lost.position + lost.velocity / TICKS_PER_SECOND;
+            let lead_position = lost.lead_position.map(|lead| lead + lost.velocity / TICKS_PER_SECOND);
+            self.target = Some(UnitDescription::create(lost.class, predicted, lost.velocity, lead_position));
+            self.radar.update_poi_scan(predicted, POI_RADIUS);
+        } else {
+            // TODO: if the target was lost after SeekToKill, or GoBoom, maybe its dead or out of reach
+            // check fuel left
+            self.target = None;
+            self.strategy = MissileStrategy::FindTarget;
+            self.radar.update_broad_scan();
+        }
+    }
+
+    // Boost onto the intercept, coast while on course, and leave the terminal phase to guidance
+    fn approach_trajectory(&mut self) {
+        self.find_target();
+        if let Some(target) = self.target {
+            let (phase, plan) = MissileEnergy::current().phase(position(), velocity(), target.position, target.velocity, self.target_acceleration());
+            self.phase = phase;
+            match (phase, plan) {
+                (MissilePhase::Boost, Some(plan)) => {
+                    turn(angle_diff(heading(), plan.direction.angle()) * 10.0);
+                    accelerate(plan.burn(max_forward_acceleration()));
+                }
+                (MissilePhase::Coast, _) => turn(angle_diff(heading(), (target.position - position()).angle()) * 10.0),
+                _ => {
+                    self.strategy = MissileStrategy::SeekToKill;
+                    self.seek_to_kill();
+                }
+            }
+            self.missile_ticks += 1;
+        }
+    }
+
+    fn target_acceleration(&self) -> Vec2 {
+        self.filter.map(|filter| filter.acceleration()).unwrap_or_default()
+    }
+
+    // Proportional navigation onto the target, nose along the commanded acceleration
+    fn seek_to_kill(&mut self) {
+        self.find_target();
+        if let Some(target) = self.target {
+            self.phase = MissilePhase::Terminal;
+            let range_difference = target.position - position();
+            let command = self
+                .guidance
+                .thrust(position(), velocity(), target.position, target.velocity, self.target_acceleration());
+            turn(angle_diff(heading(), command.angle()) * 10.0);
+            accelerate(command);
+            if self.fuse.update(position(), velocity(), target.position, target.velocity) {
+                explode();
+            } else if self.fuse.is_armed() || fuel() == 0.0 {
+                let amount_to_turn = angle_diff(heading(), range_difference.angle());
+                turn(amount_to_turn * 30.0);
+                self.strategy = MissileStrategy::GoBoom;
+            }
+        }
+    }
+
+    // The fuse picks the tick closest to the target. Out of fuel on a course that
+    // misses, there is nothing left to wait for
+    fn go_boom(&mut self) {
+        self.find_target();
+        if let Some(target) = self.target {
+            let distance = target.position - position();
+            turn(angle_diff(heading(), distance.angle()) * 20.0);
+            accelerate(
+                self.guidance
+                    .thrust(position(), velocity(), target.position, target.velocity, self.target_acceleration()),
+            );
+            let detonate = self.fuse.update(position(), velocity(), target.position, target.velocity);
+            let missing = self.fuse.predicted_miss().is_some_and(|miss| miss > self.fuse.blast_radius);
+            if detonate || (fuel() == 0.0 && missing) {
+                explode();
+            }
+        }
+    }
+
+    // High level missile procedural logic
+    pub fn tick(&mut self, _shared: &mut SharedData) {
+        self.radio.listen();
+        match self.strategy {
+            MissileStrategy::Initialize => self.initialize(),
+            MissileStrategy::FindTarget => {
+                self.find_target();
+                // nothing on radar, steer by the launcher's uplink until it is
+                self.check_for_target();
+            }
+            MissileStrategy::ApproachTrajectory => self.approach_trajectory(),
+            MissileStrategy::SeekToKill => self.seek_to_kill(),
+            MissileStrategy::GoBoom => self.go_boom(),
+        }
+        self.draw_diagnostics();
+    }
+
+    fn draw_diagnostics(&self) {
+        debug!("Missile Ticks: {}", self.missile_ticks);
+        debug!("Velocity (per sec): {}", velocity());
+        debug!("Velocity (per tick): {}", velocity() / TICKS_PER_SECOND);
+        debug!("Current Strategy: {:?}, phase {:?}", self.strategy, self.phase);
+        if let Some(target) = self.target.as_ref() {
+            let line_diff = target.position - position();
+            debug!("Target Class: {:?}", target.class);
+            debug!("Target Position: {}", target.position);
+            debug!("Distance from target: {}", line_diff.length());
+            debug!("Target velocity: {}", target.velocity);
+            debug!("Target Heading: {}", target.target_heading);
+            debug!("Position: {} ", position());
+            debug!("Velocity: {}", velocity());
+            if let Some(lead_position) = target.lead_position {
+                draw_line(lead_position, position(), 0xff0000);
+                draw_line(lead_position, target.position, 0x00ff00);
+            } else {
+                draw_line(target.position, position(), 0xff0000);
+            }
+            if let Some(last_target) = self.last_target.as_ref() {
+                draw_line(last_target.position, target.position, 0x000f0f);
+            }
+            debug!("Fuel {} ", fuel());
+            if let Some(approach) = self.fuse.approach {
+                debug!("Predicted miss {:.1}m in {:.2}s", approach.miss, approach.time);
+            }
+        }
+    }
+}
+// ******** END Missile Type ************************
+
+/******************************************************************************************
+* ** Frigate Type **
+*
+******************************************************************************************/
+const RADIO_POI_RADIUS: f64 = 500.0; // meters
+const RADIO_POI_LIFETIME: f64 = 2.0; // seconds
+const MAIN_GUN: usize = 0; // fixed, fires along the hull
+const TURRETS: [usize; 2] = [1, 2];
+const MISSILE_LAUNCHER: usize = 3;
+const MAIN_GUN_SPEED: f64 = 4000.0; // m/s
+const MAIN_GUN_RANGE: f64 = 8000.0; // meters, two seconds of flight
+const MAIN_GUN_TOLERANCE: f64 = 0.005; // radians off the lead before the main gun holds fire
+const TURRET_RANGE: f64 = 4000.0; // meters, four seconds of flight
+
+pub struct SupaFrigate {
+    ticks_till_reload_missile: u32,
+    radar: RadarScheduler,
+    radio: RadioManager,
+    targets: VecDeque<UnitDescription>,
+    dodge_or_kill: VecDeque<UnitDescription>,
+    target_lock: Option<UnitDescription>,
+    turn: Box<dyn HeadingController>,
+}
+
+impl SupaFrigate {
+    pub fn configure_frigate() -> Ship {
+        Ship {
+            cruiser_data: None,
+            frigate_data: Some(SupaFrigate {
+                ticks_till_reload_missile: 0,
+                radar: RadarScheduler::create().with_sweep(TAU / 64.0, world_size() / 2.0),
+                radio: RadioManager::for_class(
+                    Class::Frigate,
+                    &[RadioPurpose::FleetContacts, RadioPurpose::Command, RadioPurpose::MissileUplink],
+                ),
+                targets: VecDeque::new(),
+                dodge_or_kill: VecDeque::new(),
+                target_lock: None,
+                turn: heading_controller(Class::Frigate),
+            }),
+            fighter_data: None,
+            missle_data: None,
+            shared_data: SharedData::create(),
+        }
+    }
+
+    pub fn draw_targets(&self) {
+        debug!("Targets: {}", self.targets.len());
+        debug!("Dodge or kill: {}", self.dodge_or_kill.len());
+        debug!("Missile reload: {} ticks, radar {:?}", self.ticks_till_reload_missile, self.radar.task);
+        if let Some(target_lock) = self.target_lock.as_ref() {
+            draw_line(position(), target_lock.position, 0xff0000);
+        }
+        for target in self.targets.iter() {
+            draw_square(target.position, 20.0, 0xff0000);
+            draw_diamond(target.position + target.velocity, 20.0, 0x00ff00);
+            draw_diamond(target.position + (target.velocity * 2.0), 20.0, 0x00ff00);
+            draw_diamond(target.position + (target.velocity * 3.0), 20.0, 0x00ff00);
+        }
+    }
+
+    // Rebuild the target lists from the track table: ships nearest first, and the
+    // missiles and torpedoes closing on us that have to be dodged or shot down
+    pub fn update_targets(&mut self, board: &mut Blackboard) {
+        self.radar.observe(&mut board.tracks, scan());
+        let now = current_time();
+        let mut targets: Vec<UnitDescription> = board.tracks.firm().map(|track| track.describe(now)).collect();
+        targets.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        let (threats, ships): (Vec<_>, Vec<_>) = targets.into_iter().partition(|target| matches!(target.class, Class::Missile | Class::Torpedo));
+        self.dodge_or_kill = threats
+            .into_iter()
+            .filter(|threat| (threat.position - position()).dot(threat.velocity - velocity()) < 0.0)
+            .collect();
+        self.targets = ships.into_iter().collect();
+        let lock_id = self.target_lock.and_then(|target| target.id);
+        self.target_lock = self.targets.iter().find(|target| target.id == lock_id).or(self.targets.front()).copied();
+    }
+    // Point the radar for next tick's scan
+    pub fn update_radar(&mut self, board: &Blackboard) {
+        self.radar.plan(&board.tracks);
+    }
+
+    pub fn send_target_to_missle(&mut self) {
+        if let Some(target) = self.target_lock.as_ref() {
+            let message = RadioMessage::create(MessageID::TargetUpdate, target.id.unwrap_or_default(), target.position, target.velocity);
+            self.radio.queue(RadioPurpose::MissileUplink, message.with_class(target.class));
+        }
+    }
+
+    // Contacts reported by the rest of the fleet join our track table and are worth
+    // a look, lost ones a wider one
+    pub fn scan_radio(&mut self, board: &mut Blackboard) {
+        board.fleet.merge(&mut board.tracks, &self.radio);
+        match self.radio.receive(RadioPurpose::FleetContacts) {
+            Some(message) if message.id == MessageID::Contact => {
+                self.radar
+                    .add_point_of_interest(message.extrapolated_position(), RADIO_POI_RADIUS, RADIO_POI_LIFETIME);
+            }
+            Some(message) if message.id == MessageID::TrackLost => {
+                self.radar
+                    .add_point_of_interest(message.extrapolated_position(), RADIO_POI_RADIUS * 2.0, RADIO_POI_LIFETIME);
+            }
+            _ => {}
+        }
+    }
+
+    // Turn the hull onto the locked target's lead and fire the main gun once it lines up
+    pub fn aim_main_gun(&mut self) {
+        let Some(target) = self.target_lock.as_ref() else {
+            return;
+        };
+        let Some(lead_position) = intercept_point(position(), velocity(), target.position, target.velocity, MAIN_GUN_SPEED) else {
+            return;
+        };
+        let offset = lead_position - position();
+        let off_lead = angle_diff(heading(), offset.angle());
+        torque(self.turn.track(off_lead, mark_rate(lead_position, target.velocity)));
+        if off_lead.abs() < MAIN_GUN_TOLERANCE && offset.length() < MAIN_GUN_RANGE {
+            fire(MAIN_GUN);
+        }
+    }
+
+    // Sit inside main gun range of the locked target
+    pub fn keep_station(&self) {
+        if let Some(target) = self.target_lock.as_ref() {
+            accelerate(approach_and_orbit(
+                MAIN_GUN_RANGE / 4.0,
+                MAIN_GUN_RANGE / 2.0,
+                position(),
+                target.position,
+                target.velocity,
+            ));
+        }
+    }
+
+    // Each turret takes the nearest inbound missile nobody else is on, and the locked
+    // target when there are none left
+    pub fn aim_turrets(&mut self) {
+        let mut threats = self.dodge_or_kill.iter();
+        for turret in TURRETS {
+            let Some(target) = threats.next().or(self.target_lock.as_ref()) else {
+                return;
+            };
+            let Some(lead_position) = intercept_point(position(), velocity(), target.position, target.velocity, BULLET_SPEED) else {
+                continue;
+            };
+            let offset = lead_position - position();
+            aim(turret, offset.angle());
+            if offset.length() < TURRET_RANGE {
+                fire(turret);
+            }
+        }
+    }
+
+    // One missile per reload, only at a target that cannot run from it
+    pub fn launch_missiles(&mut self) {
+        self.ticks_till_reload_missile = self.ticks_till_reload_missile.saturating_sub(1);
+        let Some(target) = self.target_lock.as_ref() else {
+            return;
+        };
+        if self.ticks_till_reload_missile == 0 && MissileEnergy::missile().no_escape(position(), velocity(), target.position, target.velocity, target.class) {
+            fire(MISSILE_LAUNCHER);
+            self.ticks_till_reload_missile = MISSILE_RELOAD_TIME;
+        }
+    }
+
+    pub fn tick(&mut self, shared: &mut SharedData) {
+        let board = &mut shared.blackboard;
+        self.radio.listen();
+        self.scan_radio(board);
+        self.update_targets(board);
+        self.aim_main_gun();
+        self.keep_station();
+        self.aim_turrets();
+        self.launch_missiles();
+        self.send_target_to_missle();
+        board.fleet.share(&board.tracks, &mut self.radio);
+        self.update_radar(board);
+        self.radio.transmit();
+        self.draw_targets();
+    }
+}
+
+/******************************************************************************************
+* ** Cruiser Type **
+*
+******************************************************************************************/
+const CRUISER_TURRET: usize = 0;
+const CRUISER_TURRET_SPEED: f64 = 2000.0; // m/s
+const CRUISER_TURRET_RANGE: f64 = 4000.0; // meters, two seconds of flight
+const SALVO_SIZE: usize = 2; // launchers loaded before a salvo goes, fewer if fewer can reach
+
+pub struct ZCruiser {
+    radar: RadarScheduler,
+    radio: RadioManager,
+    slots: Vec<WeaponSlot>,
+    salvo: Option<Salvo>,
+    targets: Vec<UnitDescription>,
+    target_lock: Option<UnitDescription>,
+    turn: Box<dyn HeadingController>,
+}
+
+pub fn configure_cruiser() -> Ship {
+    Ship {
+        cruiser_data: Some(ZCruiser {
+            radar: RadarScheduler::create().with_sweep(TAU / 64.0, world_size() / 2.0),
+            radio: RadioManager::for_class(
+                Class::Cruiser,
+                &[RadioPurpose::FleetContacts, RadioPurpose::Command, RadioPurpose::MissileUplink],
+            ),
+            slots: vec![
+                WeaponSlot::create(CRUISER_TURRET, Mount::Turret, 24),
+                WeaponSlot::create(1, Mount::Launcher(Class::Missile), MISSILE_RELOAD_TIME),
+                WeaponSlot::create(2, Mount::Launcher(Class::Missile), MISSILE_RELOAD_TIME),
+                WeaponSlot::create(3, Mount::Launcher(Class::Torpedo), 480),
+            ],
+            salvo: None,
+            targets: Vec::new(),
+            target_lock: None,
+            turn: heading_controller(Class::Cruiser),
+        }),
+        frigate_data: None,
+        fighter_data: None,
+        missle_data: None,
+        shared_data: SharedData::create(),
+    }
+}
+
+impl ZCruiser {
+    // Every firm track, and the ship we are launching at
+    pub fn update_targets(&mut self, board: &mut Blackboard) {
+        self.radar.observe(&mut board.tracks, scan());
+        board.fleet.merge(&mut board.tracks, &self.radio);
+        let now = current_time();
+        self.targets = board.tracks.firm().map(|track| track.describe(now)).collect();
+        self.targets.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+        let lock_id = self.target_lock.and_then(|target| target.id);
+        let ships = || self.targets.iter().filter(|target| !matches!(target.class, Class::Missile | Class::Torpedo));
+        self.target_lock = ships().find(|target| target.id == lock_id).or(ships().next()).copied();
+    }
+
+    // Turrets go to the most threatening tracks, inbound missiles before ships
+    pub fn aim_turrets(&mut self) {
+        let turrets: Vec<usize> = self.slots.iter().filter(|slot| slot.mount == Mount::Turret).map(|slot| slot.index).collect();
+        for (turret, target) in allocate_turrets(&turrets, &self.targets) {
+            let Some(lead_position) = intercept_point(position(), velocity(), target.position, target.velocity, CRUISER_TURRET_SPEED) else {
+                continue;
+            };
+            let offset = lead_position - position();
+            aim(turret, offset.angle());
+            if offset.length() < CRUISER_TURRET_RANGE {
+                if let Some(slot) = self.slots.iter_mut().find(|slot| slot.index == turret) {
+                    slot.fire();
+                }
+            }
+        }
+    }
+
+    // Seconds a launcher's missile needs to reach the target, None if it cannot
+    // be sure to get there
+    fn flight_time(slot: &WeaponSlot, target: &UnitDescription) -> Option<f64> {
+        let Mount::Launcher(class) = slot.mount else {
+            return None;
+        };
+        let energy = MissileEnergy::for_class(class);
+        if !energy.no_escape(position(), velocity(), target.position, target.velocity, target.class) {
+            return None;
+        }
+        energy.flight_time(position(), velocity(), target.position, target.velocity)
+    }
+
+    // Start a salvo at the locked target once enough launchers are loaded, then fire
+    // each launcher when its flight time brings it in with the slowest
+    pub fn fire_salvo(&mut self) {
+        let now = current_time();
+        let Some(target) = self.target_lock else {
+            self.salvo = None;
+            return;
+        };
+        if self.salvo.as_ref().is_some_and(|salvo| Some(salvo.track) != target.id) {
+            self.salvo = None;
+        }
+        if self.salvo.is_none() {
+            // only loaded launchers are worth the envelope check
+            let launchers = self.slots.iter().filter(|slot| matches!(slot.mount, Mount::Launcher(_))).count();
+            let reloading = self.slots.iter().any(|slot| matches!(slot.mount, Mount::Launcher(_)) && !slot.is_ready());
+            let loaded: Vec<(usize, f64)> = self
+                .slots
+                .iter()
+                .filter(|slot| slot.is_ready())
+                .filter_map(|slot| Self::flight_time(slot, &target).map(|time| (slot.index, time)))
+                .collect();
+            if !loaded.is_empty() && (loaded.len() >= SALVO_SIZE.min(launchers) || !reloading) {
+                self.salvo = target.id.and_then(|track| Salvo::create(track, &loaded, now));
+            }
+        }
+        let Some(salvo) = self.salvo.as_mut() else {
+            return;
+        };
+        for slot in self.slots.iter_mut() {
+            if !salvo.is_pending(slot.index) || !slot.is_ready() {
+                continue;
+            }
+            match Self::flight_time(slot, &target) {
+                Some(time) if salvo.is_due(slot.index, time, now) => {
+                    slot.fire();
+                    salvo.launched(slot.index);
+                }
+                // out of reach now, it sits this salvo out
+                None => salvo.launched(slot.index),
+                _ => {}
+            }
+        }
+        if salvo.is_complete() {
+            self.salvo = None;
+        }
+    }
+
+    pub fn send_target_to_missiles(&mut self) {
+        if let Some(target) = self.target_lock.as_ref() {
+            let message = RadioMessage::create(MessageID::TargetUpdate, target.id.unwrap_or_default(), target.position, target.velocity);
+            self.radio.queue(RadioPurpose::MissileUplink, message.with_class(target.class));
+        }
+    }
+
+    pub fn draw_diagnostics(&self) {
+        debug!("Targets: {}", self.targets.len());
+        for slot in self.slots.iter() {
+            debug!("Slot {} {:?}: {} ticks", slot.index, slot.mount, slot.ticks_till_reload);
+        }
+        if let Some(salvo) = self.salvo.as_ref() {
+            debug!("Salvo on {} lands at {:.2}s, waiting on {:?}", salvo.track, salvo.arrival, salvo.pending);
+        }
+        if let Some(target_lock) = self.target_lock.as_ref() {
+            draw_line(position(), target_lock.position, 0xff0000);
+        }
+    }
+
+    pub fn tick(&mut self, shared: &mut SharedData) {
+        let board = &mut shared.blackboard;
+        self.radio.listen();
+        for slot in self.slots.iter_mut() {
+            slot.update();
+        }
+        self.update_targets(board);
+        if let Some(target) = self.target_lock.as_ref() {
+            let angle_to_mark = angle_diff(heading(), (target.position - position()).angle());
+            torque(self.turn.track(angle_to_mark, mark_rate(target.position, target.velocity)));
+        }
+        self.aim_turrets();
+        self.fire_salvo();
+        self.send_target_to_missiles();
+        board.fleet.share(&board.tracks, &mut self.radio);
+        self.radar.plan(&board.tracks);
+        self.radio.transmit();
+        self.draw_diagnostics();
+    }
+}
+// ******** END Cruiser Type ************************
+
+// Implementation of Ship -> aka ShipWrapper
+pub struct Ship {
+    cruiser_data: Option<ZCruiser>,
+    frigate_data: Option<SupaFrigate>,
+    fighter_data: Option<TyFighter>,
+    missle_data: Option<XMissle>,
+    shared_data: SharedData,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        match class() {
+            Class::Frigate => SupaFrigate::configure_frigate(),
+            Class::Fighter => TyFighter::configure_fighter(),
+            Class::Cruiser => configure_cruiser(),
+            Class::Missile | Class::Torpedo => XMissle::configure_missle(),
+            _ => Ship {
+                cruiser_data: None,
+                frigate_data: None,
+                fighter_data: None,
+                missle_data: None,
+                shared_data: SharedData::create(),
+            },
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.shared_data.blackboard.begin_tick();
+        if let Some(fighter) = self.fighter_data.as_mut() {
+            fighter.tick(&mut self.shared_data);
+        }
+        if let Some(cruiser) = self.cruiser_data.as_mut() {
+            cruiser.tick(&mut self.shared_data);
+        }
+        if let Some(frigate) = self.frigate_data.as_mut() {
+            frigate.tick(&mut self.shared_data);
+        }
+        if let Some(missle) = self.missle_data.as_mut() {
+            missle.tick(&mut self.shared_data);
+        }
+    }
+}
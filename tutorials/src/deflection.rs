@@ -0,0 +1,81 @@
+// Tutorial: Deflection
+// Destroy the enemy ship. Its position is given by the "target" function and velocity by the
+// "target_velocity" function.
+//
+// Hint: p = p₀ + v₀t + ½at² (the third equation of kinematics)
+// Hint: target() + target_velocity() * t gives the position of the target after t seconds.
+//
+// You can scale a vector by a number: vec2(a, b) * c == vec2(a * c, b * c)
+use oort_api::prelude::*;
+use oort_core::constants::{BULLET_SPEED, SEEK_AND_DESTROY};
+use oort_core::diagnostics::{self, TurnTest};
+use oort_core::intercept::Projectile;
+use oort_core::navigation::{spring_orbit, update_engine_vectors};
+use oort_core::targeting::{on_target, AccelerationLead, LeadTracker};
+use oort_core::turning::{HeadingController, DEFLECTION_TURN};
+use oort_core::weapons::BurstGun;
+
+/**************************************************************
+* Tutorial 6: Deflection
+* Author: Christopher Dean
+* Last Update: 10/30/23
+* Improved fast and accurate turning, now approaching target
+* 4.880s on Tutorial 6
+****************************************************************/
+pub struct Ship {
+    target_heading: Option<f64>,
+    acceleration: Option<Vec2>,
+    target_lead_position: Option<Vec2>,
+    objective: u64,
+    lead: LeadTracker,
+    gun0: BurstGun,
+    turn: Box<dyn HeadingController>,
+    turn_test: TurnTest,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            target_heading: None,
+            acceleration: None,
+            target_lead_position: None,
+            objective: SEEK_AND_DESTROY,
+            //objective: !SEEK_AND_DESTROY, // For Fun, uncomment and comment the above line
+            lead: LeadTracker::create(Projectile::bullet(), AccelerationLead::Estimated),
+            gun0: BurstGun::create(0, 10, 1), // USE To configure burst fire count
+            turn: Box::new(DEFLECTION_TURN),
+            turn_test: TurnTest::create(),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        if self.objective == SEEK_AND_DESTROY {
+            self.gun0.should_fire = on_target(self.target_heading, 0.013);
+            // update aim and tracking position
+            self.target_lead_position = self.lead.track(target(), target_velocity(), velocity());
+            self.acceleration = Some(spring_orbit(0.5, 250.0, 400.0, position(), target(), target_velocity()));
+        } else {
+            self.target_lead_position = self.turn_test.next_mark(self.target_lead_position);
+        }
+        // calculate heading from lead position
+        if let Some(lead_position) = self.target_lead_position {
+            self.target_heading = Some((lead_position - position()).angle());
+        }
+        self.gun0.update();
+        let next_torque = match (self.target_heading, self.target_lead_position) {
+            (Some(target_heading), Some(lead_position)) => self.turn.track(angle_diff(heading(), target_heading), self.lead.mark_rate(lead_position)),
+            _ => 0.0,
+        };
+        update_engine_vectors(next_torque, self.acceleration);
+        self.draw_diagnostics();
+    }
+
+    fn draw_diagnostics(&self) {
+        debug!("Angular Velocity: {}", angular_velocity());
+        debug!("Counter: {}", self.turn_test.counter);
+        debug!("Ships Heading {}", heading());
+        diagnostics::draw_target_heading(self.target_heading);
+        diagnostics::draw_target_line();
+        diagnostics::draw_lead(self.target_lead_position, BULLET_SPEED, self.lead.target_acceleration);
+    }
+}
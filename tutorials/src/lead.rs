@@ -0,0 +1,81 @@
+// Tutorial: Lead
+// Destroy the enemy ship. Its position is given by the "target" function and velocity by the
+// "target_velocity" function. Your ship is not able to accelerate in this scenario.
+//
+// This is where the game becomes challenging! You'll need to lead the target
+// by firing towards where the target will be by the time the bullet gets there.
+use oort_api::prelude::*;
+use oort_core::constants::{BULLET_SPEED, SEEK_AND_DESTROY};
+use oort_core::diagnostics::{self, TurnTest};
+use oort_core::intercept::Projectile;
+use oort_core::navigation::update_engine_vectors;
+use oort_core::targeting::{on_target, AccelerationLead, LeadTracker};
+use oort_core::turning::{BangBangTurn, HeadingController};
+use oort_core::weapons::BurstGun;
+
+/**************************************************************
+* Tutorial 5: Lead Solution
+* Author: Christopher Dean
+* Last Update: 10/30/23
+* Now has ability to swap in captain-seahorse turning functions
+* utilizing the !SEEK_AND_DESTROY unit test
+****************************************************************/
+pub struct Ship {
+    target_heading: Option<f64>,
+    target_lead_position: Option<Vec2>,
+    objective: u64,
+    lead: LeadTracker,
+    gun0: BurstGun,
+    turn_test: TurnTest,
+    turn: Box<dyn HeadingController>,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            target_heading: None,
+            target_lead_position: None,
+            objective: SEEK_AND_DESTROY,
+            //objective: !SEEK_AND_DESTROY, // For Fun, uncomment and comment the above line
+            lead: LeadTracker::create(Projectile::bullet(), AccelerationLead::Ignore),
+            gun0: BurstGun::create(0, 8, 1).with_burst_fire(), // USE To configure burst fire count
+            turn_test: TurnTest::create(),
+            turn: Box::new(BangBangTurn),
+            //turn: Box::new(SEAHORSE_TURN), // To compare, swap in any HeadingController
+        }
+    }
+
+    pub fn tick(&mut self) {
+        if self.objective == SEEK_AND_DESTROY {
+            self.gun0.should_fire = on_target(self.target_heading, 0.01);
+            // update aim and tracking position
+            self.target_lead_position = self.lead.track(target(), target_velocity(), velocity());
+        } else {
+            self.target_lead_position = self.turn_test.next_mark(self.target_lead_position);
+        }
+        // calculate heading from lead position
+        if let Some(lead_position) = self.target_lead_position {
+            self.target_heading = Some((lead_position - position()).angle());
+        }
+        self.gun0.update();
+        // this ship can not accelerate, only turn
+        update_engine_vectors(self.next_torque(), None);
+        self.draw_diagnostics();
+    }
+
+    fn next_torque(&self) -> f64 {
+        match (self.target_heading, self.target_lead_position) {
+            (Some(target_heading), Some(lead_position)) => self.turn.track(angle_diff(heading(), target_heading), self.lead.mark_rate(lead_position)),
+            _ => 0.0,
+        }
+    }
+
+    fn draw_diagnostics(&self) {
+        debug!("Angular Velocity: {}", angular_velocity());
+        debug!("Counter: {}", self.turn_test.counter);
+        debug!("Ships Heading {}", heading());
+        diagnostics::draw_target_heading(self.target_heading);
+        diagnostics::draw_target_line();
+        diagnostics::draw_lead(self.target_lead_position, BULLET_SPEED, None);
+    }
+}
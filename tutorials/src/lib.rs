@@ -0,0 +1,19 @@
+/**************************************************************
+* Tutorial solutions
+* Author: Christopher Dean
+* One module per oort.rs tutorial, each exposing the `Ship`
+* the game instantiates. Modules only use oort_api and
+* oort_core so the bundler can turn any of them into a
+* single pasteable file.
+****************************************************************/
+// The game requires `Ship::new()`; a Default impl would never be called.
+#![allow(clippy::new_without_default)]
+
+pub mod castle_missiles;
+pub mod deflection;
+pub mod lead;
+pub mod missiles;
+pub mod radar;
+pub mod radio;
+pub mod search;
+pub mod squadron;
@@ -0,0 +1,115 @@
+// Tutorial: Missiles
+// Destroy the enemy ship with your missiles.
+// Hint: https://en.wikipedia.org/wiki/Proportional_navigation
+use oort_api::prelude::*;
+use oort_core::constants::{BULLET_SPEED, SEEK_AND_DESTROY};
+use oort_core::diagnostics;
+use oort_core::intercept::Projectile;
+use oort_core::navigation::{approach_and_orbit, missile_homing, update_engine_vectors};
+use oort_core::radar::{SweepRadar, SWEEP_STEP};
+use oort_core::radio::{receive_contact, send_contact, POSITIONING_CHANNEL};
+use oort_core::targeting::{on_target, AccelerationLead, LeadTracker};
+use oort_core::turning::{heading_controller, HeadingController};
+use oort_core::weapons::BurstGun;
+
+/**************************************************************
+* Tutorial 10: Missiles
+* Author: Christopher Dean
+* Last Update: 11/05/23
+* Adjusted to using the radio on channel 2 to supply position
+* and velocity
+* 15.917 on tutorial 10
+****************************************************************/
+pub struct Ship {
+    target_heading: Option<f64>,
+    acceleration: Option<Vec2>,
+    target_lead_position: Option<Vec2>,
+    objective: u64,
+    radar: SweepRadar,
+    lead: LeadTracker,
+    gun1: BurstGun,
+    turn: Box<dyn HeadingController>,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            target_heading: None,
+            acceleration: None,
+            target_lead_position: None,
+            objective: SEEK_AND_DESTROY,
+            radar: SweepRadar::create(SWEEP_STEP).with_sweep_distance(BULLET_SPEED * 10.0),
+            lead: LeadTracker::create(Projectile::bullet(), AccelerationLead::Ignore),
+            gun1: BurstGun::create(1, 3, 1), // missile launcher
+            turn: heading_controller(class()),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        let lock_heading = self.radar.contact.map(|contact| (contact.position - position()).angle());
+        self.radar.update(lock_heading);
+        if class() == Class::Missile {
+            self.update_missile();
+        } else {
+            self.update_fighter();
+        }
+    }
+
+    fn update_fighter(&mut self) {
+        self.send_radio();
+        if self.objective == SEEK_AND_DESTROY {
+            // find and kill
+            self.gun1.should_fire = on_target(self.target_heading, 0.018);
+            self.target_lead_position = None;
+            if let Some(contact) = self.radar.contact {
+                // update aim and tracking position
+                self.target_lead_position = self.lead.track_contact(&contact, velocity());
+                self.acceleration = Some(approach_and_orbit(550.0, 1050.0, position(), contact.position, contact.velocity));
+            }
+        }
+        // calculate heading from lead position
+        self.target_heading = self.target_lead_position.map(|lead_position| (lead_position - position()).angle());
+        self.gun1.update();
+        let next_torque = match (self.target_heading, self.target_lead_position) {
+            (Some(target_heading), Some(lead_position)) => self.turn.track(angle_diff(heading(), target_heading), self.lead.mark_rate(lead_position)),
+            _ => 0.0,
+        };
+        update_engine_vectors(next_torque, self.acceleration);
+        self.draw_diagnostics();
+    }
+
+    // Share the current lead position so launched missiles can home on it
+    fn send_radio(&self) {
+        if let (Some(lead_position), Some(target_velocity)) = (self.target_lead_position, self.lead.target_velocity) {
+            send_contact(POSITIONING_CHANNEL, lead_position, target_velocity);
+        }
+    }
+
+    fn update_missile(&mut self) {
+        let contact = match self.radar.contact {
+            Some(contact) => Some((contact.position, contact.velocity)),
+            None => receive_contact(POSITIONING_CHANNEL),
+        };
+        if let Some((target_position, target_velocity)) = contact {
+            let line_diff = target_position - position();
+            self.target_heading = Some(line_diff.angle());
+            debug!("Target Position: {}", target_position);
+            debug!("Position: {} ", position());
+            debug!("Distance from target: {}", line_diff.length());
+            debug!("Velocity: {}", velocity());
+            turn(angle_diff(heading(), line_diff.angle()));
+            accelerate(missile_homing(line_diff, target_velocity));
+            draw_line(target_position, position(), 0xff0000);
+            if line_diff.length() <= 122.0 || fuel() == 0.0 {
+                explode();
+            }
+        }
+    }
+
+    fn draw_diagnostics(&self) {
+        diagnostics::draw_ship_state();
+        diagnostics::draw_target_heading(self.target_heading);
+        diagnostics::draw_target_line();
+        diagnostics::draw_lead(self.target_lead_position, BULLET_SPEED, self.lead.target_acceleration);
+    }
+}
@@ -0,0 +1,82 @@
+// Tutorial: Radar
+// Destroy the enemy ships. Use your radar to find them.
+// Hint: Press 'g' in-game to show where your radar is looking.
+// Hint: Press 'n' to single-step.
+// Hint: Use the set_radar_heading() function to keep your radar pointed at a
+// target, or to search for a new one.
+//
+// Join the Discord at https://discord.gg/vYyu9EhkKH for Oort discussion and
+// tournament results.
+use oort_api::prelude::*;
+use oort_core::constants::{BULLET_SPEED, SEEK_AND_DESTROY};
+use oort_core::diagnostics::{self, TurnTest};
+use oort_core::intercept::Projectile;
+use oort_core::navigation::update_engine_vectors;
+use oort_core::radar::{SweepRadar, SWEEP_STEP};
+use oort_core::targeting::{on_target, AccelerationLead, LeadTracker};
+use oort_core::turning::{HeadingController, DEFLECTION_TURN};
+use oort_core::weapons::BurstGun;
+
+/**************************************************************
+* Tutorial 7: Radar
+* Author: Christopher Dean
+* Last Update: 11/01/23
+* Added v1 Radar scanning
+* 13.935s on Tutorial 7
+****************************************************************/
+pub struct Ship {
+    target_heading: Option<f64>,
+    target_lead_position: Option<Vec2>,
+    objective: u64,
+    radar: SweepRadar,
+    lead: LeadTracker,
+    gun0: BurstGun,
+    turn: Box<dyn HeadingController>,
+    turn_test: TurnTest,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            target_heading: None,
+            target_lead_position: None,
+            objective: SEEK_AND_DESTROY,
+            //objective: !SEEK_AND_DESTROY, // For Fun, uncomment and comment the above line
+            radar: SweepRadar::create(SWEEP_STEP),
+            lead: LeadTracker::create(Projectile::bullet(), AccelerationLead::Ignore),
+            gun0: BurstGun::create(0, 20, 1).with_burst_fire(), // USE To configure burst fire count
+            turn: Box::new(DEFLECTION_TURN),
+            turn_test: TurnTest::create(),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        let lock_heading = self.radar.contact.map(|contact| (contact.position - position()).angle());
+        self.radar.update(lock_heading);
+        if self.objective == SEEK_AND_DESTROY {
+            self.gun0.should_fire = on_target(self.target_heading, 0.015);
+            // update aim and tracking position
+            self.target_lead_position = self.radar.contact.and_then(|contact| self.lead.track_contact(&contact, velocity()));
+        } else {
+            self.target_lead_position = self.turn_test.next_mark(self.target_lead_position);
+        }
+        // calculate heading from lead position
+        self.target_heading = self.target_lead_position.map(|lead_position| (lead_position - position()).angle());
+        self.gun0.update();
+        let next_torque = match (self.target_heading, self.target_lead_position) {
+            (Some(target_heading), Some(lead_position)) => self.turn.track(angle_diff(heading(), target_heading), self.lead.mark_rate(lead_position)),
+            _ => 0.0,
+        };
+        update_engine_vectors(next_torque, None);
+        self.draw_diagnostics();
+    }
+
+    fn draw_diagnostics(&self) {
+        debug!("Angular Velocity: {}", angular_velocity());
+        debug!("Counter: {}", self.turn_test.counter);
+        debug!("Ships Heading {}", heading());
+        diagnostics::draw_target_heading(self.target_heading);
+        diagnostics::draw_target_line();
+        diagnostics::draw_lead(self.target_lead_position, BULLET_SPEED, self.lead.target_acceleration);
+    }
+}
@@ -0,0 +1,75 @@
+// Tutorial: Radio
+// Destroy the enemy ship. Your radar is broken, but a radio signal on channel
+// 2 will give you its position and velocity.
+use oort_api::prelude::*;
+use oort_core::constants::{BULLET_SPEED, SEEK_AND_DESTROY};
+use oort_core::diagnostics;
+use oort_core::intercept::Projectile;
+use oort_core::navigation::{dash_and_orbit, update_engine_vectors};
+use oort_core::radio::{receive_contact, POSITIONING_CHANNEL};
+use oort_core::targeting::{on_target, AccelerationLead, LeadTracker};
+use oort_core::turning::{heading_controller, HeadingController};
+use oort_core::weapons::BurstGun;
+
+/**************************************************************
+* Tutorial 9: Radio
+* Author: Christopher Dean
+* Last Update: 11/03/23
+* Adjusted to using the radio on channel 2 to supply position
+* and velocity
+* 25.717s on tutorial 9
+****************************************************************/
+pub struct Ship {
+    target_heading: Option<f64>,
+    acceleration: Option<Vec2>,
+    target_lead_position: Option<Vec2>,
+    objective: u64,
+    lead: LeadTracker,
+    gun0: BurstGun,
+    turn: Box<dyn HeadingController>,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            target_heading: None,
+            acceleration: None,
+            target_lead_position: None,
+            objective: SEEK_AND_DESTROY,
+            lead: LeadTracker::create(Projectile::bullet(), AccelerationLead::Estimated).with_jitter(),
+            gun0: BurstGun::create(0, 3, 1), // USE To configure burst fire count
+            turn: heading_controller(class()),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        let contact = receive_contact(POSITIONING_CHANNEL);
+        if self.objective == SEEK_AND_DESTROY {
+            // find and kill
+            self.gun0.should_fire = on_target(self.target_heading, 0.018);
+            self.target_lead_position = None;
+            if let Some((contact_position, contact_velocity)) = contact {
+                debug!("msg: {:?}", (contact_position, contact_velocity));
+                // update aim and tracking position
+                self.target_lead_position = self.lead.track(contact_position, contact_velocity, velocity());
+                self.acceleration = Some(dash_and_orbit(300.0, 800.0, position(), contact_position, contact_velocity));
+            }
+        }
+        // calculate heading from lead position
+        self.target_heading = self.target_lead_position.map(|lead_position| (lead_position - position()).angle());
+        self.gun0.update();
+        let next_torque = match (self.target_heading, self.target_lead_position) {
+            (Some(target_heading), Some(lead_position)) => self.turn.track(angle_diff(heading(), target_heading), self.lead.mark_rate(lead_position)),
+            _ => 0.0,
+        };
+        update_engine_vectors(next_torque, self.acceleration);
+        self.draw_diagnostics();
+    }
+
+    fn draw_diagnostics(&self) {
+        diagnostics::draw_ship_state();
+        diagnostics::draw_target_heading(self.target_heading);
+        diagnostics::draw_target_line();
+        diagnostics::draw_lead(self.target_lead_position, BULLET_SPEED, self.lead.target_acceleration);
+    }
+}
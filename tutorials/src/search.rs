@@ -0,0 +1,85 @@
+// Tutorial: Radar
+// Destroy the enemy ships. Use your radar to find them.
+// Hint: Press 'g' in-game to show where your radar is looking.
+// Hint: Press 'n' to single-step.
+// Hint: Use the set_radar_heading() function to keep your radar pointed at a
+// target, or to search for a new one.
+//
+// Join the Discord at https://discord.gg/vYyu9EhkKH for Oort discussion and
+// tournament results.
+use oort_api::prelude::*;
+use oort_core::constants::{BULLET_SPEED, SEEK_AND_DESTROY};
+use oort_core::diagnostics;
+use oort_core::intercept::Projectile;
+use oort_core::navigation::{dash_and_orbit, update_engine_vectors};
+use oort_core::radar::{SweepRadar, SWEEP_STEP};
+use oort_core::targeting::{on_target, AccelerationLead, LeadTracker};
+use oort_core::turning::{heading_controller, HeadingController};
+use oort_core::weapons::BurstGun;
+
+/**************************************************************
+* Tutorial 8: Search
+* Author: Christopher Dean
+* Last Update: 11/03/23
+* Improved approach and closer to an orbit for target
+* Drastically improved lead compensation
+* Added distance based jitter improving bullet spread and
+* incresing the difficulty to dodge
+* 26.548s on Tutorial 8
+****************************************************************/
+pub struct Ship {
+    target_heading: Option<f64>,
+    acceleration: Option<Vec2>,
+    target_lead_position: Option<Vec2>,
+    objective: u64,
+    radar: SweepRadar,
+    lead: LeadTracker,
+    gun0: BurstGun,
+    turn: Box<dyn HeadingController>,
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            target_heading: None,
+            acceleration: None,
+            target_lead_position: None,
+            objective: SEEK_AND_DESTROY,
+            radar: SweepRadar::create(SWEEP_STEP).with_sweep_distance(BULLET_SPEED * 10.0),
+            lead: LeadTracker::create(Projectile::bullet(), AccelerationLead::Estimated).with_jitter(),
+            gun0: BurstGun::create(0, 3, 1), // USE To configure burst fire count
+            turn: heading_controller(class()),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        let lock_heading = self.radar.contact.map(|contact| (contact.position - position()).angle());
+        self.radar.update(lock_heading);
+        if self.objective == SEEK_AND_DESTROY {
+            // find and kill
+            self.gun0.should_fire = on_target(self.target_heading, 0.018);
+            self.target_lead_position = None;
+            if let Some(contact) = self.radar.contact {
+                // update aim and tracking position
+                self.target_lead_position = self.lead.track_contact(&contact, velocity());
+                self.acceleration = Some(dash_and_orbit(300.0, 800.0, position(), contact.position, contact.velocity));
+            }
+        }
+        // calculate heading from lead position
+        self.target_heading = self.target_lead_position.map(|lead_position| (lead_position - position()).angle());
+        self.gun0.update();
+        let next_torque = match (self.target_heading, self.target_lead_position) {
+            (Some(target_heading), Some(lead_position)) => self.turn.track(angle_diff(heading(), target_heading), self.lead.mark_rate(lead_position)),
+            _ => 0.0,
+        };
+        update_engine_vectors(next_torque, self.acceleration);
+        self.draw_diagnostics();
+    }
+
+    fn draw_diagnostics(&self) {
+        diagnostics::draw_ship_state();
+        diagnostics::draw_target_heading(self.target_heading);
+        diagnostics::draw_target_line();
+        diagnostics::draw_lead(self.target_lead_position, BULLET_SPEED, self.lead.target_acceleration);
+    }
+}
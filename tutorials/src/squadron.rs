@@ -0,0 +1,229 @@
+// Tutorial: Squadron
+// Destroy the enemy ships. They now shoot back.
+use oort_api::prelude::*;
+use oort_core::blackboard::Blackboard;
+use oort_core::constants::{BULLET_SPEED, SEEK_AND_DESTROY};
+use oort_core::coordination::{is_position_round, is_report_tick, position_report, read_assignment, Friendly, FRIENDLY_TIMEOUT};
+use oort_core::diagnostics;
+use oort_core::intercept::Projectile;
+use oort_core::navigation::{approach_and_orbit, missile_homing, update_engine_vectors};
+use oort_core::radar::{SweepRadar, SWEEP_STEP};
+use oort_core::radio::{MessageID, RadioManager, RadioMessage, RadioPurpose};
+use oort_core::targeting::{on_target, AccelerationLead, LeadTracker, UnitDescription};
+use oort_core::turning::{heading_controller, HeadingController};
+use oort_core::weapons::BurstGun;
+
+/**************************************************************
+* Tutorial 11: Squadron
+* Author: Christopher Dean
+* Last Update: 11/08/23
+* Fighters open with a staggered volley before engaging. The
+* lowest id fighter runs the HiveMind and assigns targets over
+* the command channel, every fighter steers its missiles on
+* the missile channel
+****************************************************************/
+const ASSIGNMENT_MATCH: f64 = 300.0; // meters, a radar contact this close to our assigned target is it
+
+pub struct Ship {
+    target_heading: Option<f64>,
+    acceleration: Option<Vec2>,
+    target_lead_position: Option<Vec2>,
+    objective: u64,
+    radar: SweepRadar,
+    lead: LeadTracker,
+    gun0: BurstGun,
+    turn: Box<dyn HeadingController>,
+    radio: RadioManager,
+    blackboard: Blackboard,
+    assigned: Option<(UnitDescription, f64)>, // target and when it was assigned
+}
+
+impl Ship {
+    pub fn new() -> Ship {
+        Ship {
+            target_heading: None,
+            acceleration: None,
+            target_lead_position: None,
+            objective: SEEK_AND_DESTROY,
+            radar: SweepRadar::create(SWEEP_STEP)
+                .with_sweep_distance(BULLET_SPEED * 10.0)
+                .with_class_filter(Class::Fighter),
+            lead: LeadTracker::create(Projectile::bullet(), AccelerationLead::Estimated),
+            gun0: BurstGun::create(0, 3, 1), // USE To configure burst fire count
+            turn: heading_controller(class()),
+            radio: match class() {
+                Class::Missile => RadioManager::for_class(Class::Missile, &[RadioPurpose::MissileUplink]),
+                ship_class => RadioManager::for_class(ship_class, &[RadioPurpose::Command, RadioPurpose::MissileUplink]),
+            },
+            blackboard: Blackboard::create(),
+            assigned: None,
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.blackboard.begin_tick();
+        set_radar_max_distance(world_size());
+        self.radio.listen();
+        if class() == Class::Missile {
+            self.update_missile();
+            return;
+        }
+        self.coordinate();
+        if id() == 1 {
+            let current_ticks = current_tick();
+            if current_ticks < 600 {
+                accelerate(vec2(10.0, 0.0));
+                fire(0);
+                fire(1);
+            } else if current_ticks > 600 && current_ticks < 750 {
+                accelerate(vec2(25.0, 0.0));
+                fire(0);
+                fire(1);
+            } else {
+                self.update_normal_ship();
+            }
+        } else if current_tick() < 1200 {
+            fire(0);
+            fire(1);
+            accelerate(vec2(50.0, 0.0));
+        } else {
+            self.update_normal_ship();
+        }
+        self.radio.transmit();
+    }
+
+    // Hear the squadron, run the HiveMind if we lead it, and talk in our slot
+    fn coordinate(&mut self) {
+        let now = current_time();
+        let board = &mut self.blackboard;
+        if let Some(message) = self.radio.receive(RadioPurpose::Command) {
+            board.hive.hear(&message, now);
+            if message.id == MessageID::Contact {
+                board
+                    .tracks
+                    .ingest_report(message.class, message.position, message.velocity, message.age(), now);
+            }
+            if let Some(target) = read_assignment(&message, id()) {
+                self.assigned = Some((target, now));
+            }
+        }
+        board.hive.register_ship(Friendly::own());
+        board.hive.forget(now);
+        board.tracks.update(now);
+        let leader = board.hive.leader(id()) == id();
+        if leader {
+            board.hive.assign(&board.tracks, now);
+            self.assigned = board
+                .hive
+                .assignment(id())
+                .and_then(|assignment| board.tracks.get(assignment.track))
+                .map(|track| (track.describe(now), now));
+        }
+        if !is_report_tick(id(), current_tick()) {
+            return;
+        }
+        let message = if is_position_round(current_tick()) {
+            Some(position_report())
+        } else if leader {
+            board.hive.next_message(&board.tracks, now)
+        } else {
+            self.radar
+                .contact
+                .map(|contact| RadioMessage::create(MessageID::Contact, 0, contact.position, contact.velocity).with_class(contact.class))
+        };
+        if let Some(message) = message {
+            self.radio.queue(RadioPurpose::Command, message);
+        }
+    }
+
+    // Where our assigned target should be now, while the assignment is fresh
+    fn assigned_position(&self) -> Option<Vec2> {
+        let now = current_time();
+        self.assigned
+            .filter(|(_, assigned_at)| now - assigned_at <= FRIENDLY_TIMEOUT)
+            .map(|(target, assigned_at)| target.position + target.velocity * (now - assigned_at))
+    }
+
+    // Hold the contact if it is our assigned target, otherwise look where the assignment says
+    fn update_radar(&mut self) {
+        let bearing = |point: Vec2| (point - position()).angle();
+        match (self.radar.contact, self.assigned_position()) {
+            (Some(contact), Some(assigned)) if (contact.position - assigned).length() > ASSIGNMENT_MATCH => self.radar.cue(bearing(assigned)),
+            (None, Some(assigned)) => self.radar.cue(bearing(assigned)),
+            (contact, _) => self.radar.update(contact.map(|contact| bearing(contact.position))),
+        }
+        if let Some(contact) = self.radar.contact {
+            self.blackboard.tracks.ingest(&contact);
+        }
+    }
+
+    fn update_missile(&mut self) {
+        let lock_heading = self.radar.contact.map(|contact| (contact.position - position()).angle());
+        self.radar.update(lock_heading);
+        let uplink = self
+            .radio
+            .receive(RadioPurpose::MissileUplink)
+            .filter(|message| message.id == MessageID::TargetUpdate)
+            .map(|message| (message.position, message.velocity));
+        let contact = self.radar.contact.map(|contact| (contact.position, contact.velocity)).or(uplink);
+        if let Some((target_position, target_velocity)) = contact {
+            let line_diff = target_position - position();
+            self.target_heading = Some(line_diff.angle());
+            debug!("Target Position: {}", target_position);
+            debug!("Distance from target: {}", line_diff.length());
+            turn(angle_diff(heading(), line_diff.angle()));
+            accelerate(missile_homing(line_diff, target_velocity));
+            draw_line(target_position, position(), 0xff0000);
+            if line_diff.length() <= 122.0 {
+                explode();
+            }
+        } else {
+            accelerate(vec2(1000.0, 0.0));
+        }
+    }
+
+    fn update_normal_ship(&mut self) {
+        self.update_radar();
+        self.send_radio();
+        if self.objective == SEEK_AND_DESTROY {
+            // find and kill
+            self.gun0.should_fire = on_target(self.target_heading, 0.018);
+            self.target_lead_position = None;
+            if let Some(contact) = self.radar.contact {
+                // update aim and tracking position
+                self.target_lead_position = self.lead.track_contact(&contact, velocity());
+                self.acceleration = Some(approach_and_orbit(550.0, 1050.0, position(), contact.position, contact.velocity));
+            }
+        }
+        // calculate heading from lead position
+        self.target_heading = self.target_lead_position.map(|lead_position| (lead_position - position()).angle());
+        self.gun0.update();
+        let next_torque = match (self.target_heading, self.target_lead_position) {
+            (Some(target_heading), Some(lead_position)) => self.turn.track(angle_diff(heading(), target_heading), self.lead.mark_rate(lead_position)),
+            _ => 0.0,
+        };
+        update_engine_vectors(next_torque, self.acceleration);
+        self.draw_diagnostics();
+    }
+
+    // Steer our missiles onto the lead point
+    fn send_radio(&mut self) {
+        if let (Some(lead_position), Some(target_velocity)) = (self.target_lead_position, self.lead.target_velocity) {
+            let message = RadioMessage::create(MessageID::TargetUpdate, 0, lead_position, target_velocity);
+            self.radio.queue(RadioPurpose::MissileUplink, message);
+        }
+    }
+
+    fn draw_diagnostics(&self) {
+        let board = &self.blackboard;
+        diagnostics::draw_ship_state();
+        debug!("Ticks run {}", board.ticks);
+        debug!("Hivemind leader {}, hearing {} ships", board.hive.leader(id()), board.hive.friendlies.len());
+        if let Some(assigned) = self.assigned_position() {
+            draw_line(position(), assigned, 0xffff00);
+        }
+        diagnostics::draw_target_heading(self.target_heading);
+        diagnostics::draw_target_line();
+        diagnostics::draw_lead(self.target_lead_position, BULLET_SPEED, self.lead.target_acceleration);
+    }
+}
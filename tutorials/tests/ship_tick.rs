@@ -0,0 +1,331 @@
+/**************************************************************
+* Ship tick tests
+* Each test fills in the mock environment, ticks a tutorial
+* Ship and checks the commands it handed to the game
+****************************************************************/
+use oort_api::mock::{self, Commands, Environment};
+use oort_api::prelude::*;
+use oort_core::envelope::MissileEnergy;
+use oort_core::radio::{MessageID, RadioMessage, POSITIONING_CHANNEL};
+use tutorials::{castle_missiles, lead, missiles, radio, squadron};
+
+fn contact(class: Class, position: Vec2, velocity: Vec2) -> ScanResult {
+    ScanResult {
+        class,
+        position,
+        velocity,
+        rssi: 0.0,
+        snr: 30.0,
+    }
+}
+
+fn tick(tick: impl FnOnce()) -> Commands {
+    tick();
+    mock::with(|env| env.tick += 1);
+    mock::take_commands()
+}
+
+#[test]
+fn lead_fires_once_nose_is_on_target() {
+    let target = vec2(1000.0, 1.0);
+    let mut env = Environment::for_class(Class::Fighter);
+    env.target = target;
+    env.heading = target.angle();
+    mock::install(env);
+    let mut ship = lead::Ship::new();
+
+    let first = tick(|| ship.tick());
+    assert!(first.fired.is_empty(), "no heading to compare against on the first tick");
+
+    let second = tick(|| ship.tick());
+    assert_eq!(second.fired, vec![0]);
+    assert_eq!(second.acceleration, Some(vec2(0.0, 0.0)));
+    assert!(second.torque.is_some());
+}
+
+#[test]
+fn lead_turns_toward_target() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.target = vec2(1000.0, 1000.0);
+    mock::install(env);
+    let mut ship = lead::Ship::new();
+
+    let commands = tick(|| ship.tick());
+    assert!(commands.torque.unwrap() > 0.0);
+    assert!(commands.fired.is_empty());
+}
+
+#[test]
+fn lead_leads_by_the_ship_velocity_not_its_position() {
+    // a still target dead ahead of a still ship needs no lead, wherever the ship sits
+    let (ship, target) = (vec2(-3000.0, 2000.0), vec2(1000.0, 1.0));
+    let mut env = Environment::for_class(Class::Fighter);
+    env.position = ship;
+    env.target = target;
+    env.heading = (target - ship).angle();
+    mock::install(env);
+    let mut ship = lead::Ship::new();
+
+    tick(|| ship.tick());
+    assert_eq!(tick(|| ship.tick()).fired, vec![0], "nose already on the target");
+}
+
+#[test]
+fn missile_explodes_inside_blast_radius() {
+    let mut env = Environment::for_class(Class::Missile);
+    env.scan_result = Some(contact(Class::Fighter, vec2(100.0, 10.0), vec2(0.0, 0.0)));
+    mock::install(env);
+    let mut ship = missiles::Ship::new();
+
+    let commands = tick(|| ship.tick());
+    assert!(commands.exploded);
+    assert!(commands.acceleration.is_some());
+}
+
+#[test]
+fn missile_holds_fire_outside_blast_radius() {
+    let mut env = Environment::for_class(Class::Missile);
+    env.scan_result = Some(contact(Class::Fighter, vec2(3000.0, 500.0), vec2(0.0, 0.0)));
+    mock::install(env);
+    let mut ship = missiles::Ship::new();
+
+    let commands = tick(|| ship.tick());
+    assert!(!commands.exploded);
+    assert!(commands.turn.is_some());
+}
+
+#[test]
+fn missiles_fighter_shares_lead_on_positioning_channel() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.scan_result = Some(contact(Class::Fighter, vec2(3000.0, 500.0), vec2(-20.0, 5.0)));
+    mock::install(env);
+    let mut ship = missiles::Ship::new();
+
+    let first = tick(|| ship.tick());
+    assert!(first.sent.is_empty(), "nothing to share until a lead is computed");
+
+    let second = tick(|| ship.tick());
+    assert_eq!(second.sent.len(), 1);
+    assert_eq!(second.sent[0].channel, POSITIONING_CHANNEL);
+    assert_eq!(second.sent[0].message[2..], [-20.0, 5.0]);
+}
+
+#[test]
+fn radio_ship_steers_on_received_contact() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.radios[0].inbox = Some([5000.0, 500.0, 0.0, 0.0]);
+    mock::install(env);
+    let mut ship = radio::Ship::new();
+
+    let commands = tick(|| ship.tick());
+    assert_eq!(mock::environment().radios[0].channel, POSITIONING_CHANNEL);
+    assert!(commands.acceleration.unwrap().length() > 0.0);
+    assert!(commands.torque.unwrap() > 0.0);
+}
+
+#[test]
+fn squadron_missile_flies_forward_without_contact() {
+    mock::install(Environment::for_class(Class::Missile));
+    let mut ship = squadron::Ship::new();
+
+    let commands = tick(|| ship.tick());
+    assert_eq!(commands.acceleration, Some(vec2(1000.0, 0.0)));
+    assert!(!commands.exploded);
+}
+
+#[test]
+fn castle_missile_steers_on_typed_target_update() {
+    let update = RadioMessage {
+        id: MessageID::TargetUpdate,
+        sender: 3,
+        track: 9,
+        position: vec2(0.0, 4000.0),
+        velocity: vec2(0.0, 0.0),
+        class: Class::Fighter,
+        tick: 0,
+    };
+    let mut env = Environment::for_class(Class::Missile);
+    env.radios[0].inbox = Some(update.encode());
+    mock::install(env);
+    let mut ship = castle_missiles::Ship::new();
+
+    tick(|| ship.tick());
+    let radar = mock::environment().radar;
+    assert!(angle_diff(radar.heading, PI / 2.0).abs() < 0.01, "narrow scan on the reported target");
+}
+
+#[test]
+fn castle_missile_ignores_untyped_contacts() {
+    let mut env = Environment::for_class(Class::Missile);
+    env.radios[0].inbox = Some([0.0, 4000.0, 0.0, 0.0]);
+    mock::install(env);
+    let mut ship = castle_missiles::Ship::new();
+
+    tick(|| ship.tick());
+    assert_eq!(mock::environment().radar.width, TAU / 16.0, "still on the broad scan");
+    assert!(angle_diff(mock::environment().radar.heading, PI / 2.0).abs() > 0.5);
+}
+
+#[test]
+fn castle_fighter_engages_a_track_the_fleet_reported() {
+    let report = RadioMessage {
+        id: MessageID::Contact,
+        sender: 3,
+        track: 1,
+        position: vec2(500.0, 3000.0),
+        velocity: vec2(0.0, 0.0),
+        class: Class::Frigate,
+        tick: 0,
+    };
+    let mut env = Environment::for_class(Class::Fighter);
+    env.radios[0].inbox = Some(report.encode());
+    mock::install(env);
+    let mut ship = castle_missiles::Ship::new();
+
+    let first = tick(|| ship.tick());
+    let commands = tick(|| ship.tick());
+    assert!(commands.torque.unwrap() > 0.0, "turning toward the report without a radar contact");
+    assert!(
+        first.sent.iter().chain(commands.sent.iter()).all(|sent| sent.radio != 0),
+        "relayed tracks are not shared again"
+    );
+    let uplink = commands.sent.iter().find(|sent| sent.radio == 1).unwrap();
+    let update = RadioMessage::decode(uplink.message).unwrap();
+    assert_eq!((update.id, update.class), (MessageID::TargetUpdate, Class::Frigate));
+}
+
+#[test]
+fn squadron_follower_looks_where_the_leader_assigns() {
+    let leader = |id: MessageID, track: u32, position: Vec2, tick: u32| RadioMessage {
+        id,
+        sender: 0,
+        track,
+        position,
+        velocity: vec2(0.0, 0.0),
+        class: Class::Fighter,
+        tick,
+    };
+    let mut env = Environment::for_class(Class::Fighter);
+    env.id = 1;
+    env.tick = 800;
+    env.radios[0].inbox = Some(leader(MessageID::Position, 0, vec2(0.0, -500.0), 800).encode());
+    mock::install(env);
+    let mut ship = squadron::Ship::new();
+    tick(|| ship.tick());
+
+    mock::with(|env| env.radios[0].inbox = Some(leader(MessageID::Assignment, 1, vec2(0.0, 3000.0), 801).encode()));
+    tick(|| ship.tick());
+    assert!(
+        angle_diff(mock::environment().radar.heading, PI / 2.0).abs() < 0.01,
+        "radar cued on the assigned target"
+    );
+}
+
+#[test]
+fn castle_fighter_keeps_its_tracks_on_the_blackboard() {
+    let mut env = Environment::for_class(Class::Fighter);
+    env.scan_result = Some(contact(Class::Fighter, vec2(-800.0, 1500.0), vec2(0.0, 0.0)));
+    mock::install(env);
+    let mut ship = castle_missiles::Ship::new();
+    for _ in 0..3 {
+        tick(|| ship.tick());
+    }
+
+    mock::with(|env| env.scan_result = None);
+    let commands = tick(|| ship.tick());
+    assert!(commands.torque.unwrap() > 0.0, "still turning onto the track after the radar lost it");
+}
+
+#[test]
+fn castle_fighter_launches_only_inside_the_no_escape_zone() {
+    let fired = |target: Vec2| {
+        let mut env = Environment::for_class(Class::Fighter);
+        env.heading = target.angle();
+        env.scan_result = Some(contact(Class::Fighter, target, vec2(0.0, 0.0)));
+        mock::install(env);
+        let mut ship = castle_missiles::Ship::new();
+        (0..4).any(|_| tick(|| ship.tick()).fired.contains(&1))
+    };
+    assert!(fired(vec2(500.0, 3000.0)));
+    assert!(!fired(vec2(500.0, 30000.0)), "a fighter this far out can outrun the missile");
+}
+
+#[test]
+fn castle_frigate_engages_a_track_and_uplinks_its_missiles() {
+    let target = vec2(500.0, 3000.0);
+    let mut env = Environment::for_class(Class::Frigate);
+    env.scan_result = Some(contact(Class::Fighter, target, vec2(0.0, 0.0)));
+    mock::install(env);
+    let mut ship = castle_missiles::Ship::new();
+    let ticks: Vec<Commands> = (0..6).map(|_| tick(|| ship.tick())).collect();
+
+    let engaged = ticks
+        .iter()
+        .position(|commands| commands.fired.contains(&3))
+        .expect("a missile at a fighter this close");
+    let commands = &ticks[engaged];
+    assert!(commands.torque.unwrap() > 0.0, "hull turning onto the lead");
+    assert!(!commands.fired.contains(&0), "main gun holds until it lines up");
+    for turret in [1, 2] {
+        let (_, heading) = commands.aimed.iter().find(|(aimed, _)| *aimed == turret).unwrap();
+        assert!(angle_diff(*heading, target.angle()).abs() < 0.01);
+        assert!(commands.fired.contains(&turret));
+    }
+    assert!(ticks[engaged + 1..].iter().all(|later| !later.fired.contains(&3)), "reloading");
+    let uplink = ticks[engaged..]
+        .iter()
+        .flat_map(|commands| commands.sent.iter())
+        .filter_map(|sent| RadioMessage::decode(sent.message))
+        .find(|message| message.id == MessageID::TargetUpdate)
+        .expect("target update for the missiles");
+    assert_eq!(uplink.class, Class::Fighter);
+    assert!((uplink.position - target).length() < 1.0);
+}
+
+#[test]
+fn castle_frigate_turns_a_turret_on_an_inbound_missile() {
+    let ship_position = vec2(500.0, 3000.0);
+    let (missile_start, missile_velocity) = (vec2(2000.0, 500.0), vec2(-400.0, -100.0));
+    mock::install(Environment::for_class(Class::Frigate));
+    let mut ship = castle_missiles::Ship::new();
+    let mut last = Commands::default();
+    for step in 0..10 {
+        let missile = missile_start + missile_velocity * (step as f64 / 60.0);
+        let scanned = if step % 2 == 0 {
+            contact(Class::Fighter, ship_position, vec2(0.0, 0.0))
+        } else {
+            contact(Class::Missile, missile, missile_velocity)
+        };
+        mock::with(|env| env.scan_result = Some(scanned));
+        last = tick(|| ship.tick());
+    }
+    let heading = |turret: usize| last.aimed.iter().find(|(aimed, _)| *aimed == turret).unwrap().1;
+    assert!(angle_diff(heading(1), missile_start.angle()).abs() < 0.1, "first turret on the missile");
+    assert!(angle_diff(heading(2), ship_position.angle()).abs() < 0.01, "second turret on the ship");
+}
+
+#[test]
+fn castle_cruiser_times_its_salvo_to_land_together() {
+    let target = vec2(500.0, 4000.0);
+    let mut env = Environment::for_class(Class::Cruiser);
+    env.scan_result = Some(contact(Class::Frigate, target, vec2(0.0, 0.0)));
+    mock::install(env);
+    let mut ship = castle_missiles::Ship::new();
+    let mut launched = Vec::new();
+    for step in 0..600 {
+        let commands = tick(|| ship.tick());
+        launched.extend(commands.fired.iter().filter(|index| **index != 0).map(|index| (*index, step)));
+    }
+    let first = |launcher: usize| launched.iter().find(|(index, _)| *index == launcher).unwrap().1;
+    let (origin, still) = (vec2(0.0, 0.0), vec2(0.0, 0.0));
+    let flight_time = |class| MissileEnergy::for_class(class).flight_time(origin, still, target, still).unwrap();
+    let torpedo_lands = first(3) as f64 / 60.0 + flight_time(Class::Torpedo);
+    for missile in [1, 2] {
+        let lands = first(missile) as f64 / 60.0 + flight_time(Class::Missile);
+        assert!(
+            (lands - torpedo_lands).abs() < 2.0 / 60.0,
+            "launcher {missile} lands at {lands}, torpedo {torpedo_lands}"
+        );
+    }
+    assert!(first(3) < first(1), "the slow torpedo goes first");
+}